@@ -0,0 +1,229 @@
+use crate::core::{complex::Complex, modp::ModP, number::RealNumber, rational::Rational};
+
+// How close two values must be to count as equal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance<E> {
+    // |a - b| <= epsilon.
+    Absolute(E),
+    // |a - b| <= epsilon, or |a - b| <= max_relative * max(|a|, |b|).
+    Relative { epsilon: E, max_relative: E },
+    // |a - b| <= epsilon, or a and b are at most max_ulps representable values apart.
+    Ulps { epsilon: E, max_ulps: u32 },
+}
+
+// Equality up to round-off. Exact types use () as their epsilon and compare with ==.
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+
+    fn approx_eq_with(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        match tolerance {
+            Tolerance::Absolute(epsilon) => self.abs_diff_eq(other, epsilon),
+            Tolerance::Relative { epsilon, max_relative } => self.relative_eq(other, epsilon, max_relative),
+            Tolerance::Ulps { epsilon, max_ulps } => self.ulps_eq(other, epsilon, max_ulps),
+        }
+    }
+}
+
+macro_rules! impl_float_approx_eq {
+    ($($t:ty, $bits:ty),*) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> Self::Epsilon {
+                    <$t>::EPSILON
+                }
+
+                fn default_max_relative() -> Self::Epsilon {
+                    <$t>::EPSILON
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                    (self - other).abs() <= epsilon
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    let diff = (self - other).abs();
+                    if diff <= epsilon {
+                        return true;
+                    }
+                    diff <= self.abs().max(other.abs()) * max_relative
+                }
+
+                // Floats of one sign order like their bit patterns, so the distance between
+                // the patterns counts the representable values between them. NaN is never
+                // equal, and an infinity only equals itself rather than the largest finite value.
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() || self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true;
+                    }
+                    if self.is_sign_positive() != other.is_sign_positive() {
+                        return false;
+                    }
+                    let a = self.to_bits() as $bits;
+                    let b = other.to_bits() as $bits;
+                    a.abs_diff(b) <= max_ulps as _
+                }
+            }
+        )*
+    };
+}
+
+impl_float_approx_eq!(f32, i32, f64, i64);
+
+impl<T: RealNumber + ApproxEq<Epsilon = T>> ApproxEq for Complex<T> {
+    type Epsilon = T;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        (*self - *other).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        if self == other {
+            return true;
+        }
+        let diff = (*self - *other).abs();
+        if diff <= epsilon {
+            return true;
+        }
+        let a = self.abs();
+        let b = other.abs();
+        diff <= if a > b { a } else { b } * max_relative
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.abs_diff_eq(other, epsilon)
+            || (self.re.ulps_eq(&other.re, epsilon, max_ulps) && self.im.ulps_eq(&other.im, epsilon, max_ulps))
+    }
+}
+
+impl ApproxEq for Rational {
+    type Epsilon = ();
+
+    fn default_epsilon() -> Self::Epsilon {}
+
+    fn default_max_relative() -> Self::Epsilon {}
+
+    fn abs_diff_eq(&self, other: &Self, _epsilon: Self::Epsilon) -> bool {
+        self == other
+    }
+
+    fn relative_eq(&self, other: &Self, _epsilon: Self::Epsilon, _max_relative: Self::Epsilon) -> bool {
+        self == other
+    }
+
+    fn ulps_eq(&self, other: &Self, _epsilon: Self::Epsilon, _max_ulps: u32) -> bool {
+        self == other
+    }
+}
+
+impl<const P: u64> ApproxEq for ModP<P> {
+    type Epsilon = ();
+
+    fn default_epsilon() -> Self::Epsilon {}
+
+    fn default_max_relative() -> Self::Epsilon {}
+
+    fn abs_diff_eq(&self, other: &Self, _epsilon: Self::Epsilon) -> bool {
+        self == other
+    }
+
+    fn relative_eq(&self, other: &Self, _epsilon: Self::Epsilon, _max_relative: Self::Epsilon) -> bool {
+        self == other
+    }
+
+    fn ulps_eq(&self, other: &Self, _epsilon: Self::Epsilon, _max_ulps: u32) -> bool {
+        self == other
+    }
+}
+
+// Sequences are equal when they have the same length and agree element by element.
+impl<T: ApproxEq> ApproxEq for [T] {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self[..].abs_diff_eq(&other[..], epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self[..].relative_eq(&other[..], epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self[..].ulps_eq(&other[..], epsilon, max_ulps)
+    }
+}
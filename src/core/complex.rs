@@ -1,6 +1,6 @@
 use std::{cmp::Ordering, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
 
-use crate::core::number::{Number, RealNumber};
+use crate::core::{number::{Field, Number, Ring, RealNumber}, rational::Rational};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Complex<T: RealNumber> {
@@ -8,25 +8,44 @@ pub struct Complex<T: RealNumber> {
     pub im: T,
 }
 
-impl<T: RealNumber> Number for Complex<T> {
+impl<T: RealNumber> Ring for Complex<T> {
     fn zero() -> Self { Self { re: T::zero(), im: T::zero() } }
     fn one() -> Self { Self { re: T::one(), im: T::zero() } }
+    fn valid(self) -> bool {
+        self.re.valid() && self.im.valid()
+    }
+    // The ordering is lexicographic, so the size is the modulus instead.
+    fn magnitude(self) -> Self {
+        Self { re: self.abs(), im: T::zero() }
+    }
+}
+
+impl<T: RealNumber> Field for Complex<T> {
     fn inverse(self) -> Self {
-        let dem = self.re * self.re + self.im + self.im;
+        let dem = self.re * self.re + self.im * self.im;
         Self {
             re: self.re / dem,
             im: -self.im / dem,
         }
     }
-    fn valid(self) -> bool {
-        self.re.valid() && self.im.valid()
-    }
 }
 
 impl<T: RealNumber> Complex<T> {
     pub fn conjugate(self) -> Complex<T> {
         Complex { re: self.re, im: -self.im }
     }
+
+    // The modulus |z|, scaled to avoid overflow in re^2 + im^2.
+    pub fn abs(self) -> T {
+        let re = self.re.abs();
+        let im = self.im.abs();
+        let (big, small) = if re > im { (re, im) } else { (im, re) };
+        if big == T::zero() {
+            return T::zero();
+        }
+        let ratio = small / big;
+        big * (T::one() + ratio * ratio).sqrt()
+    }
 }
 
 impl<T: RealNumber> Add<> for Complex<T> {
@@ -51,8 +70,8 @@ impl<T: RealNumber> Mul<> for Complex<T> {
     fn mul(self, rhs: Self) -> Self::Output {
         Self {
             re: self.re * rhs.re - self.im * rhs.im,
-            im: self.re * rhs.im + self.im + rhs.re,
-        } 
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
     }
 }
 
@@ -70,8 +89,8 @@ impl<T: RealNumber> Div<> for Complex<T> {
 
 impl<T: RealNumber> AddAssign<Self> for Complex<T> {
     fn add_assign(&mut self, rhs: Self) {
-        self.re = self.re + rhs.re;
-        self.im = self.im - rhs.im;
+        self.re += rhs.re;
+        self.im += rhs.im;
     }
 }
 
@@ -84,16 +103,13 @@ impl<T: RealNumber> SubAssign<Self> for Complex<T> {
 
 impl<T: RealNumber> MulAssign<Self> for Complex<T> {
     fn mul_assign(&mut self, rhs: Self) {
-        self.re = self.re * rhs.re - self.im * rhs.im;
-        self.im = self.re * rhs.im + self.im + rhs.re;
+        *self = *self * rhs;
     }
 }
 
 impl<T: RealNumber> DivAssign<Self> for Complex<T> {
     fn div_assign(&mut self, rhs: Self) {
-        let dem = rhs.re * rhs.re + rhs.im * rhs.im;
-        self.re = (self.re * rhs.re + self.im * rhs.im) / dem;
-        self.im = (self.im * rhs.re - self.re * rhs.im) / dem;
+        *self = *self / rhs;
     }
 }
 
@@ -147,11 +163,11 @@ impl<T: RealNumber> PartialOrd for Complex<T> {
     }
 
     fn ge(&self, other: &Self) -> bool {
-        self == other || self > other
+        !self.lt(other)
     }
 
     fn le(&self, other: &Self) -> bool {
-        self == other || self < other
+        !self.gt(other)
     }
 }
 
@@ -164,3 +180,18 @@ impl<T: RealNumber> ComplexNumber for Complex<T> {
         self.conjugate()
     }
 }
+
+// Real numbers are their own conjugates, so Hermitian forms reduce to the usual ones.
+macro_rules! impl_real_conjugate {
+    ($($t:ty),*) => {
+        $(
+            impl ComplexNumber for $t {
+                fn conjugate(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_real_conjugate!(f32, f64, Rational);
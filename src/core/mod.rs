@@ -1,3 +1,5 @@
 pub mod complex;
 pub mod rational;
 pub mod number;
+pub mod modp;
+pub mod approx_eq;
@@ -0,0 +1,179 @@
+use std::{fmt, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
+
+use crate::core::number::{Field, Ring};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModP<const P: u64> {
+    value: u64,
+}
+
+impl<const P: u64> Ring for ModP<P> {
+    fn zero() -> Self { Self { value: 0 } }
+    fn one() -> Self { Self { value: 1 % P } }
+    fn valid(self) -> bool {
+        self.value < P
+    }
+}
+
+impl<const P: u64> Field for ModP<P> {
+    fn inverse(self) -> Self {
+        let (mut old_r, mut r) = (self.value as i128, P as i128);
+        let (mut old_s, mut s) = (1i128, 0i128);
+        while r != 0 {
+            let quotient = old_r / r;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+        }
+        assert_eq!(old_r, 1, "{} has no inverse modulo {}", self.value, P);
+        Self { value: old_s.rem_euclid(P as i128) as u64 }
+    }
+}
+
+impl<const P: u64> ModP<P> {
+    // floor((2^128 - 1) / P), the Barrett constant for reducing 128 bit products.
+    const MU: u128 = u128::MAX / P as u128;
+
+    pub fn new(value: u64) -> Self {
+        Self { value: value % P }
+    }
+
+    pub fn from_i64(value: i64) -> Self {
+        Self { value: (value as i128).rem_euclid(P as i128) as u64 }
+    }
+
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut acc = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        acc
+    }
+
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        let quotient = Self::mul_high(x, Self::MU);
+        let mut rem = x - quotient * P as u128;
+        while rem >= P as u128 {
+            rem -= P as u128;
+        }
+        rem as u64
+    }
+
+    #[inline(always)]
+    fn mul_high(a: u128, b: u128) -> u128 {
+        let (a_lo, a_hi) = (a as u64 as u128, a >> 64);
+        let (b_lo, b_hi) = (b as u64 as u128, b >> 64);
+        let lo_lo = a_lo * b_lo;
+        let hi_lo = a_hi * b_lo;
+        let lo_hi = a_lo * b_hi;
+        let hi_hi = a_hi * b_hi;
+        let cross = (lo_lo >> 64) + (hi_lo as u64 as u128) + (lo_hi as u64 as u128);
+        hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64)
+    }
+}
+
+impl<const P: u64> From<u64> for ModP<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for ModP<P> {
+    fn from(value: i64) -> Self {
+        Self::from_i64(value)
+    }
+}
+
+impl<const P: u64> Add for ModP<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (sum, overflow) = self.value.overflowing_add(rhs.value);
+        if overflow || sum >= P {
+            Self { value: sum.wrapping_sub(P) }
+        } else {
+            Self { value: sum }
+        }
+    }
+}
+
+impl<const P: u64> Sub for ModP<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.value >= rhs.value {
+            Self { value: self.value - rhs.value }
+        } else {
+            Self { value: self.value.wrapping_sub(rhs.value).wrapping_add(P) }
+        }
+    }
+}
+
+impl<const P: u64> Mul for ModP<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { value: Self::reduce(self.value as u128 * rhs.value as u128) }
+    }
+}
+
+impl<const P: u64> Div for ModP<P> {
+    type Output = Self;
+
+    // Division is multiplication by the inverse.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<const P: u64> AddAssign for ModP<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for ModP<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for ModP<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> DivAssign for ModP<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> Neg for ModP<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if self.value == 0 {
+            self
+        } else {
+            Self { value: P - self.value }
+        }
+    }
+}
+
+impl<const P: u64> fmt::Display for ModP<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -1,63 +1,178 @@
 use std::{fmt::Debug, num::FpCategory, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
 
-pub trait Number:
+pub trait Ring:
     Copy
     + Debug
     + Add<Output = Self>
     + Sub<Output = Self>
     + Mul<Output = Self>
-    + Div<Output = Self>
     + Neg<Output = Self>
     + AddAssign<Self>
     + SubAssign<Self>
     + MulAssign<Self>
-    + DivAssign<Self>
     + PartialEq<Self>
     + PartialOrd<Self>
 {
     fn zero() -> Self;
     fn one() -> Self;
-    fn inverse(self) -> Self;
     fn valid(self) -> bool;
+
+    // The size of self as an element of the same type, for pivoting and tolerance tests. The
+    // default relies on the ordering; types not ordered by size override it.
+    fn magnitude(self) -> Self {
+        if self < Self::zero() { -self } else { self }
+    }
+}
+
+pub trait Field:
+    Ring
+    + Div<Output = Self>
+    + DivAssign<Self>
+{
+    fn inverse(self) -> Self;
 }
 
-impl Number for f32 {
+pub trait Number: Field {}
+
+impl<T: Field> Number for T {}
+
+// A ring where division is only required to be correct when it is exact.
+pub trait IntegralDomain: Ring {
+    fn exact_div(self, rhs: Self) -> Self;
+}
+
+impl<T: Field> IntegralDomain for T {
+    fn exact_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+}
+
+impl Ring for f32 {
     fn zero() -> Self { 0.0 }
     fn one() -> Self { 1.0 }
+    fn valid(self) -> bool {
+        !matches!(self.classify(), FpCategory::Nan | FpCategory::Infinite)
+    }
+}
+
+impl Field for f32 {
     fn inverse(self) -> Self {
         1.0 / self
     }
-    fn valid(self) -> bool {
-        match self.classify() {
-            FpCategory::Nan | FpCategory::Infinite => false,
-            _ => true
-        }
-    }
 }
 
-impl Number for f64 {
+impl Ring for f64 {
     fn zero() -> Self { 0.0 }
     fn one() -> Self { 1.0 }
+    fn valid(self) -> bool {
+        !matches!(self.classify(), FpCategory::Nan | FpCategory::Infinite)
+    }
+}
+
+impl Field for f64 {
     fn inverse(self) -> Self {
         1.0 / self
     }
-    fn valid(self) -> bool {
-        match self.classify() {
-            FpCategory::Nan | FpCategory::Infinite => false,
-            _ => true
-        }
-    }
+}
+
+macro_rules! impl_integer_ring {
+    ($($t:ty),*) => {
+        $(
+            impl Ring for $t {
+                fn zero() -> Self { 0 }
+                fn one() -> Self { 1 }
+                fn valid(self) -> bool { true }
+            }
+
+            impl IntegralDomain for $t {
+                fn exact_div(self, rhs: Self) -> Self {
+                    debug_assert_eq!(self % rhs, 0);
+                    self / rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_ring!(i8, i16, i32, i64, i128, isize);
+
+// n as an element of the ring, built exactly from ones, for small constants in generic code.
+pub(crate) fn int<T: Ring>(n: usize) -> T {
+    (0..n).fold(T::zero(), |acc, _| acc + T::one())
 }
 
 pub trait RealNumber: Number {
     fn power(self, other: Self) -> Self;
-}    
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn acos(self) -> Self;
+}
 
 
 impl RealNumber for f32 {
     fn power(self, other: Self) -> Self { f32::powf(self, other) }
+    fn sqrt(self) -> Self { f32::sqrt(self) }
+    fn abs(self) -> Self { f32::abs(self) }
+    fn acos(self) -> Self { f32::acos(self) }
 }
 
 impl RealNumber for f64 {
     fn power(self, other: Self) -> Self { f64::powf(self, other) }
-}
\ No newline at end of file
+    fn sqrt(self) -> Self { f64::sqrt(self) }
+    fn abs(self) -> Self { f64::abs(self) }
+    fn acos(self) -> Self { f64::acos(self) }
+}
+
+// A real type paired with the widest type available for accumulating its intermediate results.
+pub trait Widen: RealNumber {
+    type Wide: RealNumber;
+    fn widen(self) -> Self::Wide;
+    fn narrow(wide: Self::Wide) -> Self;
+
+    // The unit roundoff of extended_residual, before the result is rounded back to Self.
+    fn extended_epsilon() -> Self;
+
+    // b - a . x accumulated in more precision than Self, by default in Self::Wide.
+    fn extended_residual(b: Self, a: &[Self], x: &[Self]) -> Self {
+        Self::narrow(a.iter().zip(x.iter()).fold(b.widen(), |acc, (a, x)| acc - a.widen() * x.widen()))
+    }
+}
+
+impl Widen for f32 {
+    type Wide = f64;
+    fn widen(self) -> Self::Wide { self as f64 }
+    fn narrow(wide: Self::Wide) -> Self { wide as f32 }
+    fn extended_epsilon() -> Self { f64::EPSILON as f32 }
+}
+
+// There is no wider hardware float, so the residual is accumulated as an unevaluated sum of
+// two f64s instead: every product and sum is split into its rounded value and its exact
+// error, and the errors are summed alongside (Ogita, Rump and Oishi's Dot2). The result is as
+// accurate as if computed in twice the precision.
+impl Widen for f64 {
+    type Wide = f64;
+    fn widen(self) -> Self::Wide { self }
+    fn narrow(wide: Self::Wide) -> Self { wide }
+    fn extended_epsilon() -> Self { f64::EPSILON * f64::EPSILON }
+
+    fn extended_residual(b: Self, a: &[Self], x: &[Self]) -> Self {
+        let mut sum = b;
+        let mut error = 0.0;
+        for (a, x) in a.iter().zip(x.iter()) {
+            let product = -a * x;
+            let product_error = (-a).mul_add(*x, -product);
+            let (next, sum_error) = two_sum(sum, product);
+            sum = next;
+            error += sum_error + product_error;
+        }
+        sum + error
+    }
+}
+
+// a + b as the rounded sum and its exact rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
@@ -1,6 +1,6 @@
-use std::{cmp::Ordering, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
+use std::{cmp::Ordering, fmt, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
 
-use crate::core::number::Number;
+use crate::core::number::{Field, Ring};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Rational {
@@ -9,15 +9,18 @@ pub struct Rational {
     pub den: u64,
 }
 
-impl Number for Rational {
+impl Ring for Rational {
     fn zero() -> Self { Self { pos: true, num: 0, den: 1 } }
     fn one() -> Self { Self {pos: true, num: 1, den: 1} }
-    fn inverse(self) -> Self { Self { pos: self.pos, num: self.den, den: self.num } }
     fn valid(self) -> bool {
         self.den != 0
     }
 }
 
+impl Field for Rational {
+    fn inverse(self) -> Self { Self { pos: self.pos, num: self.den, den: self.num } }
+}
+
 impl Rational {
     fn gcd(mut a: u64, mut b: u64) -> u64 {
         while b != 0 {
@@ -55,10 +58,10 @@ impl Add for Rational {
         match (self.pos, rhs.pos) {
           (true, true) => output = Self { pos: true, num: nums.1 + nums.2, den: nums.0 },
           (true, false) | (false, true) => {
-            if nums.1 > nums.2 {
-                output = Self { pos: true, num: nums.1 - nums.2, den: nums.0 }
+            if nums.1 >= nums.2 {
+                output = Self { pos: self.pos, num: nums.1 - nums.2, den: nums.0 }
             } else {
-                output = Self { pos: false, num: nums.2 - nums.1, den: nums.0 }
+                output = Self { pos: rhs.pos, num: nums.2 - nums.1, den: nums.0 }
             }
           },
           (false, false) => output = Self { pos: false, num: nums.1 + nums.2, den: nums.0 },
@@ -96,6 +99,8 @@ impl Mul for Rational {
 impl Div for Rational {
     type Output = Self;
 
+    // Division is multiplication by the reciprocal.
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: Self) -> Self::Output {
         self * rhs.inverse()
     }
@@ -139,60 +144,36 @@ impl PartialEq for Rational {
             true
         } else if self.pos != other.pos {
             false
-        } else if self.num * other.den == other.num * self.den {
-            true
         } else {
-            false
+            self.num as u128 * other.den as u128 == other.num as u128 * self.den as u128
         }
     }
 }
 
 impl PartialOrd for Rational {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self > other {
-            Some(Ordering::Greater)
-        } else if self < other {
-            Some(Ordering::Less)
-        } else if self == other {
-            Some(Ordering::Equal)
-        } else {
-            None
-        }
-    }
-
-    fn gt(&self, other: &Self) -> bool {
-        match (self.pos, other.pos) {
-            (true, true) => self.num * other.den > other.num * self.den,
-            (false, false) => self.num * other.den < other.num * self.den,
-            (true, false) => true,
-            (false, true) => false,
-        }
-    }
-
-    fn lt(&self, other: &Self) -> bool {
-        match (self.pos, other.pos) {
-            (true, true) => self.num * other.den < other.num * self.den,
-            (false, false) => self.num * other.den > other.num * self.den,
-            (true, false) => true,
-            (false, true) => false,
+        // The cross products of two u64 fractions always fit in u128; the signs are compared
+        // first, with zero counted as non-negative whichever way it is flagged.
+        let lhs = self.num as u128 * other.den as u128;
+        let rhs = other.num as u128 * self.den as u128;
+        match (self.pos || self.num == 0, other.pos || other.num == 0) {
+            (true, true) => lhs.partial_cmp(&rhs),
+            (false, false) => rhs.partial_cmp(&lhs),
+            (true, false) => Some(Ordering::Greater),
+            (false, true) => Some(Ordering::Less),
         }
     }
+}
 
-    fn ge(&self, other: &Self) -> bool {
-        match (self.pos, other.pos) {
-            (true, true) => self.num * other.den >= other.num * self.den,
-            (false, false) => self.num * other.den <= other.num * self.den,
-            (true, false) => true,
-            (false, true) => false,
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.pos && self.num != 0 {
+            write!(f, "-")?;
         }
-    }
-
-    fn le(&self, other: &Self) -> bool {
-        match (self.pos, other.pos) {
-            (true, true) => self.num * other.den <= other.num * self.den,
-            (false, false) => self.num * other.den >= other.num * self.den,
-            (true, false) => true,
-            (false, true) => false,
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
         }
     }
-}
\ No newline at end of file
+}
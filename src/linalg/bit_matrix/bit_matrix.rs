@@ -0,0 +1,194 @@
+use std::{fmt, ptr::swap};
+
+use crate::{core::{modp::ModP, number::Ring}, linalg::matrix::matrix::Matrix};
+
+pub const WORD_BITS: usize = 64;
+
+pub struct BitMatrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub row_words: usize,
+    pub data: Vec<u64>
+}
+
+impl BitMatrix {
+    pub fn zeroes(rows: usize, cols: usize) -> Self {
+        let row_words = cols.div_ceil(WORD_BITS);
+        Self {
+            rows,
+            cols,
+            row_words,
+            data: vec![0; rows * row_words]
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut m = Self::zeroes(size, size);
+        for i in 0..size {
+            m.set(i, i, true);
+        }
+        m
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        assert!(row < self.rows);
+        assert!(col < self.cols);
+        unsafe { self.u_get(row, col) }
+    }
+
+    pub unsafe fn u_get(&self, row: usize, col: usize) -> bool {
+        let word = unsafe { *self.data.get_unchecked(row * self.row_words + col / WORD_BITS) };
+        (word >> (col % WORD_BITS)) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, val: bool) {
+        assert!(row < self.rows);
+        assert!(col < self.cols);
+        unsafe { self.u_set(row, col, val) }
+    }
+
+    pub unsafe fn u_set(&mut self, row: usize, col: usize, val: bool) {
+        let word = unsafe { self.data.get_unchecked_mut(row * self.row_words + col / WORD_BITS) };
+        let mask = 1u64 << (col % WORD_BITS);
+        if val {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    pub fn swap_rows(&mut self, row1: usize, row2: usize) {
+        assert!(row1 < self.rows);
+        assert!(row2 < self.rows);
+        unsafe { self.u_swap_rows(row1, row2) }
+    }
+
+    pub unsafe fn u_swap_rows(&mut self, row1: usize, row2: usize) {
+        if row1 == row2 {
+            return;
+        }
+        let row1_offset = row1 * self.row_words;
+        let row2_offset = row2 * self.row_words;
+        for word in 0..self.row_words {
+            unsafe {
+                swap(
+                    self.data.as_mut_ptr().add(row1_offset + word),
+                    self.data.as_mut_ptr().add(row2_offset + word),
+                );
+            }
+        }
+    }
+
+    pub fn xor_rows(&mut self, des: usize, src: usize) {
+        assert!(des < self.rows);
+        assert!(src < self.rows);
+        assert_ne!(des, src);
+        unsafe { self.u_xor_rows(des, src, 0) }
+    }
+
+    // Adds row src into row des over GF(2), skipping the words before start_word.
+    pub unsafe fn u_xor_rows(&mut self, des: usize, src: usize, start_word: usize) {
+        let des_offset = des * self.row_words;
+        let src_offset = src * self.row_words;
+        for word in start_word..self.row_words {
+            unsafe {
+                *self.data.get_unchecked_mut(des_offset + word) ^= *self.data.get_unchecked(src_offset + word);
+            }
+        }
+    }
+
+    pub unsafe fn u_xor_words(&mut self, des: usize, src: *const u64, start_word: usize) {
+        let des_offset = des * self.row_words;
+        for word in start_word..self.row_words {
+            unsafe {
+                *self.data.get_unchecked_mut(des_offset + word) ^= *src.add(word);
+            }
+        }
+    }
+
+    // Reads up to 64 bits of a row starting at col, with col as the lowest bit.
+    pub unsafe fn u_read_bits(&self, row: usize, col: usize, width: usize) -> u64 {
+        let offset = row * self.row_words + col / WORD_BITS;
+        let shift = col % WORD_BITS;
+        let mut bits = unsafe { *self.data.get_unchecked(offset) } >> shift;
+        if shift + width > WORD_BITS {
+            bits |= unsafe { *self.data.get_unchecked(offset + 1) } << (WORD_BITS - shift);
+        }
+        if width < WORD_BITS {
+            bits &= (1u64 << width) - 1;
+        }
+        bits
+    }
+
+    pub fn row_data(&self, row: usize) -> &[u64] {
+        assert!(row < self.rows);
+        &self.data[row * self.row_words..(row + 1) * self.row_words]
+    }
+
+    pub fn size(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn to_matrix(&self) -> Matrix<ModP<2>> {
+        let mut m = Matrix::zeroes(self.rows, self.cols);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                if unsafe { self.u_get(row, col) } {
+                    m[(row, col)] = ModP::one();
+                }
+            }
+        }
+        m
+    }
+}
+
+impl From<&Matrix<ModP<2>>> for BitMatrix {
+    fn from(value: &Matrix<ModP<2>>) -> Self {
+        assert!(value.well_formed());
+        let mut m = Self::zeroes(value.rows, value.cols);
+        for row in 0..value.rows {
+            for col in 0..value.cols {
+                if value[(row, col)] != ModP::zero() {
+                    unsafe { m.u_set(row, col, true) };
+                }
+            }
+        }
+        m
+    }
+}
+
+impl Clone for BitMatrix {
+    fn clone(&self) -> Self {
+        Self { rows: self.rows, cols: self.cols, row_words: self.row_words, data: self.data.clone() }
+    }
+}
+
+impl PartialEq for BitMatrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && self.data == other.data
+    }
+}
+
+impl fmt::Display for BitMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            write!(f, "[")?;
+            for j in 0..self.cols {
+                write!(f, "{}", unsafe { self.u_get(i, j) } as u8)?;
+            }
+            write!(f, "]")?;
+            if i < self.rows - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::linalg::bit_matrix::bit_matrix::{BitMatrix, WORD_BITS};
+
+// Number of pivots combined into one lookup table by the Method of Four Russians.
+pub const M4RI_BLOCK: usize = 8;
+
+impl BitMatrix {
+
+    // Returns the pivot columns of the reduced form.
+    pub fn reduced_row_echelon_form(&mut self) -> Vec<usize> {
+        unsafe { self.u_reduced_row_echelon_form(self.rows, self.cols) }
+    }
+
+    pub unsafe fn u_reduced_row_echelon_form(&mut self, rows: usize, cols: usize) -> Vec<usize> {
+        let mut pivots = Vec::with_capacity(rows.min(cols));
+        let mut table = vec![0u64; (1 << M4RI_BLOCK) * self.row_words];
+        let mut start_row: usize = 0;
+        let mut start_col: usize = 0;
+        unsafe {
+            while start_row < rows && start_col < cols {
+                let width = M4RI_BLOCK.min(cols - start_col);
+                let block_pivots = self.u_block_pivots(start_row, start_col, width, rows);
+                let found = block_pivots.len();
+                if found > 0 {
+                    let start_word = start_col / WORD_BITS;
+                    self.u_combination_table(&mut table, start_row, found, start_word);
+                    for row in (0..start_row).chain(start_row + found..rows) {
+                        let mut index = 0;
+                        for (bit, col) in block_pivots.iter().enumerate() {
+                            index |= (self.u_get(row, *col) as usize) << bit;
+                        }
+                        if index != 0 {
+                            self.u_xor_words(row, table.as_ptr().add(index * self.row_words), start_word);
+                        }
+                    }
+                    pivots.extend(block_pivots);
+                }
+                start_row += found;
+                start_col += width;
+            }
+        }
+        pivots
+    }
+
+    // Finds up to width pivots in the column block, leaving the pivot rows at start_row
+    // reduced against each other. Rows outside the pivot rows are left untouched.
+    unsafe fn u_block_pivots(&mut self, start_row: usize, start_col: usize, width: usize, rows: usize) -> Vec<usize> {
+        let start_word = start_col / WORD_BITS;
+        let mut pivot_cols = Vec::with_capacity(width);
+        let mut pivot_offsets: Vec<usize> = Vec::with_capacity(width);
+        let mut pivot_bits: Vec<u64> = Vec::with_capacity(width);
+        unsafe {
+            for offset in 0..width {
+                let pivot_row = start_row + pivot_cols.len();
+                if pivot_row >= rows {
+                    break;
+                }
+                let found = (pivot_row..rows).find(|row| {
+                    let mut bits = self.u_read_bits(*row, start_col, width);
+                    for (pivot_offset, pivot) in pivot_offsets.iter().zip(pivot_bits.iter()) {
+                        if (bits >> pivot_offset) & 1 == 1 {
+                            bits ^= pivot;
+                        }
+                    }
+                    (bits >> offset) & 1 == 1
+                });
+                let Some(row) = found else {
+                    continue;
+                };
+                let col = start_col + offset;
+                self.u_swap_rows(row, pivot_row);
+                for (i, pivot_col) in pivot_cols.iter().enumerate() {
+                    if self.u_get(pivot_row, *pivot_col) {
+                        self.u_xor_rows(pivot_row, start_row + i, start_word);
+                    }
+                }
+                for i in 0..pivot_cols.len() {
+                    if self.u_get(start_row + i, col) {
+                        self.u_xor_rows(start_row + i, pivot_row, start_word);
+                    }
+                }
+                pivot_cols.push(col);
+                pivot_offsets.push(offset);
+                pivot_bits.clear();
+                for i in 0..pivot_cols.len() {
+                    pivot_bits.push(self.u_read_bits(start_row + i, start_col, width));
+                }
+            }
+        }
+        pivot_cols
+    }
+
+    // Fills table with every sum of the count rows at start_row, indexed by the bitmask of rows used.
+    unsafe fn u_combination_table(&self, table: &mut [u64], start_row: usize, count: usize, start_word: usize) {
+        let row_words = self.row_words;
+        table[start_word..row_words].fill(0);
+        for index in 1..(1usize << count) {
+            let prev = (index & (index - 1)) * row_words;
+            let src = (start_row + index.trailing_zeros() as usize) * row_words;
+            for word in start_word..row_words {
+                unsafe {
+                    *table.get_unchecked_mut(index * row_words + word) = *table.get_unchecked(prev + word) ^ *self.data.get_unchecked(src + word);
+                }
+            }
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().reduced_row_echelon_form().len()
+    }
+
+    // Returns a basis of the null space as the rows of a matrix.
+    pub fn null_space(&self) -> BitMatrix {
+        let mut reduced = self.clone();
+        let pivots = reduced.reduced_row_echelon_form();
+        let mut is_pivot = vec![false; self.cols];
+        for col in pivots.iter() {
+            is_pivot[*col] = true;
+        }
+        let free_cols: Vec<usize> = (0..self.cols).filter(|col| !is_pivot[*col]).collect();
+        let mut basis = BitMatrix::zeroes(free_cols.len(), self.cols);
+        unsafe {
+            for (i, free_col) in free_cols.iter().enumerate() {
+                basis.u_set(i, *free_col, true);
+                for (row, pivot_col) in pivots.iter().enumerate() {
+                    if reduced.u_get(row, *free_col) {
+                        basis.u_set(i, *pivot_col, true);
+                    }
+                }
+            }
+        }
+        basis
+    }
+}
@@ -0,0 +1,47 @@
+use std::ops::Mul;
+
+use crate::linalg::bit_matrix::{bit_matrix::BitMatrix, bit_matrix_elimination::M4RI_BLOCK};
+
+#[inline(always)]
+pub fn gen_bit_matrix_mult(a: &BitMatrix, b: &BitMatrix) -> BitMatrix {
+    assert_eq!(a.cols, b.rows);
+    unsafe { u_gen_bit_matrix_mult(a, b, a.rows, a.cols) }
+}
+
+// Method of Four Russians multiplication: every block of rows of b is tabulated once,
+// then each row of a selects its table entry with the matching bits.
+pub unsafe fn u_gen_bit_matrix_mult(a: &BitMatrix, b: &BitMatrix, m: usize, n: usize) -> BitMatrix {
+    let mut des = BitMatrix::zeroes(m, b.cols);
+    let row_words = b.row_words;
+    let mut table = vec![0u64; (1 << M4RI_BLOCK) * row_words];
+    let mut start = 0;
+    unsafe {
+        while start < n {
+            let width = M4RI_BLOCK.min(n - start);
+            for index in 1..(1usize << width) {
+                let prev = (index & (index - 1)) * row_words;
+                let src = (start + index.trailing_zeros() as usize) * row_words;
+                for word in 0..row_words {
+                    *table.get_unchecked_mut(index * row_words + word) = *table.get_unchecked(prev + word) ^ *b.data.get_unchecked(src + word);
+                }
+            }
+            for row in 0..m {
+                let index = a.u_read_bits(row, start, width) as usize;
+                if index != 0 {
+                    des.u_xor_words(row, table.as_ptr().add(index * row_words), 0);
+                }
+            }
+            start += width;
+        }
+    }
+    des
+}
+
+impl Mul for &BitMatrix {
+    type Output = BitMatrix;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        gen_bit_matrix_mult(self, rhs)
+    }
+}
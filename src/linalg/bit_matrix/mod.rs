@@ -0,0 +1,3 @@
+pub mod bit_matrix;
+pub mod bit_matrix_elimination;
+pub mod bit_matrix_mult;
\ No newline at end of file
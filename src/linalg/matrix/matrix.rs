@@ -1,17 +1,17 @@
 use std::{fmt, ops::{Index, IndexMut}, ptr::swap};
 
-use crate::core::number::Number;
+use crate::core::{approx_eq::ApproxEq, number::Ring};
 
-pub struct Matrix<T: Number> {
+pub struct Matrix<T: Ring> {
     pub rows: usize,
     pub cols: usize,
     pub data: Vec<T>
 }
 
-impl<T: Number> Matrix<T> {
+impl<T: Ring> Matrix<T> {
     pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
         assert_eq!(rows * cols, data.len());
-        Self { rows: rows, cols: cols, data: data }
+        Self { rows, cols, data }
     }
 
     pub unsafe fn get(&self, index: usize) -> &T {
@@ -61,7 +61,91 @@ impl<T: Number> Matrix<T> {
                     self.data.as_mut_ptr().add(row2_offset + col),
                 );
             }
-        }   
+        }
+    }
+
+    pub fn scale_row(&mut self, row: usize, factor: T) {
+        assert!(self.well_formed());
+        assert!(row < self.rows);
+        unsafe { self.u_scale_row(row, factor, self.cols) };
+    }
+
+    pub unsafe fn u_scale_row(&mut self, row: usize, factor: T, cols: usize) {
+        let row_offset = row * cols;
+        for col in 0..cols {
+            unsafe {
+                *self.get_mut(row_offset + col) *= factor;
+            }
+        }
+    }
+
+    pub fn add_row_multiple(&mut self, target: usize, source: usize, factor: T) {
+        assert!(self.well_formed());
+        assert!(target < self.rows);
+        assert!(source < self.rows);
+        assert_ne!(target, source);
+        unsafe { self.u_add_row_multiple(target, source, factor, self.cols) };
+    }
+
+    pub unsafe fn u_add_row_multiple(&mut self, target: usize, source: usize, factor: T, cols: usize) {
+        let target_offset = target * cols;
+        let source_offset = source * cols;
+        for col in 0..cols {
+            unsafe {
+                let val = factor * *self.get(source_offset + col);
+                *self.get_mut(target_offset + col) += val;
+            }
+        }
+    }
+
+    pub fn swap_cols(&mut self, col1: usize, col2: usize) {
+        assert!(self.well_formed());
+        assert!(col1 < self.cols);
+        assert!(col2 < self.cols);
+        unsafe { self.u_swap_cols(col1, col2, self.rows, self.cols) };
+    }
+
+    pub unsafe fn u_swap_cols(&mut self, col1: usize, col2: usize, rows: usize, cols: usize) {
+        for row in 0..rows {
+            unsafe {
+                swap(
+                    self.data.as_mut_ptr().add(row * cols + col1),
+                    self.data.as_mut_ptr().add(row * cols + col2),
+                );
+            }
+        }
+    }
+
+    pub fn scale_col(&mut self, col: usize, factor: T) {
+        assert!(self.well_formed());
+        assert!(col < self.cols);
+        unsafe { self.u_scale_col(col, factor, self.rows, self.cols) };
+    }
+
+    pub unsafe fn u_scale_col(&mut self, col: usize, factor: T, rows: usize, cols: usize) {
+        for row in 0..rows {
+            unsafe {
+                *self.get_mut(row * cols + col) *= factor;
+            }
+        }
+    }
+
+    pub fn add_col_multiple(&mut self, target: usize, source: usize, factor: T) {
+        assert!(self.well_formed());
+        assert!(target < self.cols);
+        assert!(source < self.cols);
+        assert_ne!(target, source);
+        unsafe { self.u_add_col_multiple(target, source, factor, self.rows, self.cols) };
+    }
+
+    pub unsafe fn u_add_col_multiple(&mut self, target: usize, source: usize, factor: T, rows: usize, cols: usize) {
+        for row in 0..rows {
+            let row_offset = row * cols;
+            unsafe {
+                let val = factor * *self.get(row_offset + source);
+                *self.get_mut(row_offset + target) += val;
+            }
+        }
     }
 
     pub fn size(&self) -> usize {
@@ -91,10 +175,44 @@ impl<T: Number> Matrix<T> {
     pub fn index_to_location(&self, index: usize) -> (usize, usize) {
         (index / self.cols, index % self.cols)
     }
-    
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows);
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.rows);
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn by_rows(&self) -> RowIndexed<'_, T> {
+        RowIndexed { matrix: self }
+    }
+
+    pub fn by_rows_mut(&mut self) -> RowIndexedMut<'_, T> {
+        RowIndexedMut { matrix: self }
+    }
+
+    pub fn trace(&self) -> T {
+        assert!(self.well_formed());
+        assert!(self.square());
+        unsafe { self.u_trace(self.rows) }
+    }
+
+    pub unsafe fn u_trace(&self, size: usize) -> T {
+        let mut acc = T::zero();
+        for i in 0..size {
+            unsafe {
+                acc += *self.get(i * size + i);
+            }
+        }
+        acc
+    }
+
 }
 
-impl<T: Number> Index<(usize, usize)> for Matrix<T> {
+impl<T: Ring> Index<(usize, usize)> for Matrix<T> {
     type Output = T;
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         assert!(index.0 < self.rows);
@@ -103,7 +221,7 @@ impl<T: Number> Index<(usize, usize)> for Matrix<T> {
     }
 }
 
-impl<T: Number> IndexMut<(usize, usize)> for Matrix<T> {
+impl<T: Ring> IndexMut<(usize, usize)> for Matrix<T> {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         assert!(index.0 < self.rows);
         assert!(index.1 < self.cols);
@@ -111,26 +229,55 @@ impl<T: Number> IndexMut<(usize, usize)> for Matrix<T> {
     }
 }
 
-impl<T: Number> Index<usize> for Matrix<T> {
+impl<T: Ring> Index<usize> for Matrix<T> {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
         &self.data[index]
     }
 }
 
-impl<T:Number> IndexMut<usize> for Matrix<T> {
+impl<T: Ring> IndexMut<usize> for Matrix<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.data[index]
     }
 }
 
-impl<T: Number> Clone for Matrix<T> {
+// Indexing through by_rows selects whole rows instead of flat elements.
+pub struct RowIndexed<'a, T: Ring> {
+    matrix: &'a Matrix<T>,
+}
+
+pub struct RowIndexedMut<'a, T: Ring> {
+    matrix: &'a mut Matrix<T>,
+}
+
+impl<T: Ring> Index<usize> for RowIndexed<'_, T> {
+    type Output = [T];
+    fn index(&self, index: usize) -> &Self::Output {
+        self.matrix.row(index)
+    }
+}
+
+impl<T: Ring> Index<usize> for RowIndexedMut<'_, T> {
+    type Output = [T];
+    fn index(&self, index: usize) -> &Self::Output {
+        self.matrix.row(index)
+    }
+}
+
+impl<T: Ring> IndexMut<usize> for RowIndexedMut<'_, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.matrix.row_mut(index)
+    }
+}
+
+impl<T: Ring> Clone for Matrix<T> {
     fn clone(&self) -> Self {
         Self { rows: self.rows, cols: self.cols, data: self.data.clone() }
     }
 }
 
-impl<T: Number + fmt::Display> fmt::Display for Matrix<T> {
+impl<T: Ring + fmt::Display> fmt::Display for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for i in 0..self.rows {
             write!(f, "[")?;
@@ -149,4 +296,30 @@ impl<T: Number + fmt::Display> fmt::Display for Matrix<T> {
     }
 }
 
+impl<T: Ring + ApproxEq> ApproxEq for Matrix<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
 
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.shape() == other.shape() && self.data.abs_diff_eq(&other.data, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.shape() == other.shape() && self.data.relative_eq(&other.data, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.shape() == other.shape() && self.data.ulps_eq(&other.data, epsilon, max_ulps)
+    }
+}
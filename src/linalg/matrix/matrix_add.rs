@@ -1,8 +1,8 @@
-use std::ops::Add;
+use std::ops::{Add, AddAssign};
 
-use crate::{core::number::Number, linalg::{matrix::matrix::Matrix, vector::util::non_zeroed_vec}};
+use crate::{core::number::Ring, linalg::{matrix::matrix::Matrix, vector::util::zeroed_vec}};
 
-impl<T: Number> Matrix<T> {
+impl<T: Ring> Matrix<T> {
     #[inline(always)]
     pub fn add(&mut self, other: &Matrix<T>) {
         assert!(self.well_formed());
@@ -24,7 +24,7 @@ impl<T: Number> Matrix<T> {
 }
 
 #[inline(always)]
-pub fn gen_matrix_add<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+pub fn gen_matrix_add<T: Ring>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
     assert!(a.well_formed());
     assert!(b.well_formed());
     assert_eq!(a.shape(), b.shape());
@@ -35,12 +35,12 @@ pub fn gen_matrix_add<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_add<T: Number>(a: &Matrix<T>, b: &Matrix<T>, size: usize) -> Matrix<T> {
+pub unsafe fn u_gen_matrix_add<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, size: usize) -> Matrix<T> {
     unsafe {
         let mut des = Matrix {
             rows: a.rows,
             cols: a.cols,
-            data: non_zeroed_vec(size),
+            data: zeroed_vec(size),
         };
         u_gen_matrix_iadd(a, b, &mut des, size);
         des
@@ -48,7 +48,7 @@ pub unsafe fn u_gen_matrix_add<T: Number>(a: &Matrix<T>, b: &Matrix<T>, size: us
 }
 
 #[inline(always)]
-pub fn gen_matrix_iadd<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
+pub fn gen_matrix_iadd<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
     assert!(a.well_formed());
     assert!(b.well_formed());
     assert!(des.well_formed());
@@ -60,7 +60,7 @@ pub fn gen_matrix_iadd<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_iadd<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
+pub unsafe fn u_gen_matrix_iadd<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
     for i in 0..size {
         unsafe {
             *des.get_mut(i) = *a.get(i) + *b.get(i);
@@ -68,11 +68,54 @@ pub unsafe fn u_gen_matrix_iadd<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &m
     }
 }
 
-impl<T: Number> Add for &Matrix<T> {
+impl<T: Ring> Add for &Matrix<T> {
     type Output = Matrix<T>;
 
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
         gen_matrix_add(self, rhs)
     }
+}
+
+impl<T: Ring> Add<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn add(mut self, rhs: &Matrix<T>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Ring> Add<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn add(self, mut rhs: Matrix<T>) -> Self::Output {
+        rhs += self;
+        rhs
+    }
+}
+
+impl<T: Ring> Add for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl<T: Ring> AddAssign<&Matrix<T>> for Matrix<T> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        Matrix::add(self, rhs);
+    }
+}
+
+impl<T: Ring> AddAssign for Matrix<T> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        Matrix::add(self, &rhs);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,124 @@
+use crate::{core::number::IntegralDomain, linalg::matrix::matrix::Matrix};
+
+impl<T: IntegralDomain> Matrix<T> {
+
+    // Returns the rank and whether an odd number of row swaps was performed.
+    pub fn bareiss_row_echelon_form(&mut self) -> (usize, bool) {
+        assert!(self.well_formed());
+        unsafe { self.u_bareiss_row_echelon_form(self.rows, self.cols) }
+    }
+
+    pub unsafe fn u_bareiss_row_echelon_form(&mut self, rows: usize, cols: usize) -> (usize, bool) {
+        let mut start_col: usize = 0;
+        let mut start_row: usize = 0;
+        let mut row_offset: usize = 0;
+        let mut prev_pivot = T::one();
+        let mut odd_swaps = false;
+        unsafe {
+            while start_row < rows && start_col < cols {
+                let Some(pivot_index) = self.u_bareiss_pivot(start_row, start_col, rows, cols) else {
+                    start_col += 1;
+                    continue;
+                };
+                if pivot_index != start_row {
+                    self.u_swap_rows(pivot_index, start_row, cols);
+                    odd_swaps = !odd_swaps;
+                }
+                let pivot = *self.get(row_offset + start_col);
+                for row in start_row+1..rows {
+                    let inner_row_offset = row * cols;
+                    let factor = *self.get(inner_row_offset + start_col);
+                    *self.get_mut(inner_row_offset + start_col) = T::zero();
+                    for col in start_col+1..cols {
+                        let val = pivot * *self.get(inner_row_offset + col) - factor * *self.get(row_offset + col);
+                        *self.get_mut(inner_row_offset + col) = val.exact_div(prev_pivot);
+                    }
+                }
+                prev_pivot = pivot;
+                start_row += 1;
+                row_offset += cols;
+                start_col += 1;
+            }
+        }
+        (start_row, odd_swaps)
+    }
+
+    // Returns the pivot columns. Every pivot entry ends up equal to the last pivot,
+    // which is the common denominator of the reduced form.
+    pub fn bareiss_reduced_row_echelon_form(&mut self) -> Vec<usize> {
+        assert!(self.well_formed());
+        unsafe { self.u_bareiss_reduced_row_echelon_form(self.rows, self.cols) }
+    }
+
+    pub unsafe fn u_bareiss_reduced_row_echelon_form(&mut self, rows: usize, cols: usize) -> Vec<usize> {
+        let mut start_col: usize = 0;
+        let mut start_row: usize = 0;
+        let mut row_offset: usize = 0;
+        let mut prev_pivot = T::one();
+        let mut pivots = Vec::with_capacity(rows.min(cols));
+        unsafe {
+            while start_row < rows && start_col < cols {
+                let Some(pivot_index) = self.u_bareiss_pivot(start_row, start_col, rows, cols) else {
+                    start_col += 1;
+                    continue;
+                };
+                if pivot_index != start_row {
+                    self.u_swap_rows(pivot_index, start_row, cols);
+                }
+                let pivot = *self.get(row_offset + start_col);
+                for row in 0..rows {
+                    if row != start_row {
+                        let inner_row_offset = row * cols;
+                        let factor = *self.get(inner_row_offset + start_col);
+                        *self.get_mut(inner_row_offset + start_col) = T::zero();
+                        for col in 0..cols {
+                            if col != start_col {
+                                let val = pivot * *self.get(inner_row_offset + col) - factor * *self.get(row_offset + col);
+                                *self.get_mut(inner_row_offset + col) = val.exact_div(prev_pivot);
+                            }
+                        }
+                    }
+                }
+                pivots.push(start_col);
+                prev_pivot = pivot;
+                start_row += 1;
+                row_offset += cols;
+                start_col += 1;
+            }
+        }
+        pivots
+    }
+
+    pub fn determinant_bareiss(&self) -> T {
+        assert!(self.well_formed());
+        assert!(self.square());
+        unsafe { self.u_determinant_bareiss(self.rows) }
+    }
+
+    pub unsafe fn u_determinant_bareiss(&self, size: usize) -> T {
+        if size == 0 {
+            return T::one();
+        }
+        let mut copy = self.clone();
+        let (rank, odd_swaps) = unsafe { copy.u_bareiss_row_echelon_form(size, size) };
+        if rank < size {
+            return T::zero();
+        }
+        let det = unsafe { *copy.get(size * size - 1) };
+        if odd_swaps { -det } else { det }
+    }
+
+    pub fn rank_bareiss(&self) -> usize {
+        assert!(self.well_formed());
+        unsafe { self.u_rank_bareiss(self.rows, self.cols) }
+    }
+
+    pub unsafe fn u_rank_bareiss(&self, rows: usize, cols: usize) -> usize {
+        let mut copy = self.clone();
+        unsafe { copy.u_bareiss_row_echelon_form(rows, cols).0 }
+    }
+
+    unsafe fn u_bareiss_pivot(&self, start_row: usize, col: usize, rows: usize, cols: usize) -> Option<usize> {
+        (start_row..rows).find(|row| unsafe { *self.get(row * cols + col) != T::zero() })
+    }
+}
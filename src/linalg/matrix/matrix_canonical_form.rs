@@ -0,0 +1,314 @@
+use crate::{core::{number::{Number, Ring}, rational::Rational}, linalg::{matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult, matrix_vector_mult::gen_matrix_vector_mult}, polynomial::polynomial::Polynomial}};
+
+// A = P * F * P^-1 with F block diagonal, holding the companion matrices of the invariant
+// factors f_1 | f_2 | ... | f_k in order.
+pub struct RationalCanonicalForm {
+    pub f: Matrix<Rational>,
+    pub p: Matrix<Rational>,
+    pub invariant_factors: Vec<Polynomial<Rational>>,
+}
+
+// A = P * J * P^-1 with J block diagonal in Jordan blocks, each an eigenvalue down the diagonal
+// with ones on the superdiagonal. blocks lists the eigenvalue and size of each block in order.
+pub struct JordanForm {
+    pub j: Matrix<Rational>,
+    pub p: Matrix<Rational>,
+    pub blocks: Vec<(Rational, usize)>,
+}
+
+impl Matrix<Rational> {
+
+    // The Frobenius normal form. With x acting as A, the column space is a module over T[x]
+    // presented by x * I - A, and a Smith form U(x) * (x * I - A) * V(x) = diag(1, ..., 1, f_1, ..., f_k)
+    // splits it into cyclic summands T[x] / (f_i). Summand i is generated by g, column i of
+    // U(x)^-1 with x replaced by A, and contributes g, A * g, ..., A^(deg f_i - 1) * g to P.
+    pub fn rational_canonical_form(&self) -> RationalCanonicalForm {
+        assert!(self.well_formed());
+        assert!(self.square());
+        let size = self.rows;
+        let (diagonal, u_inverse) = polynomial_smith_form(self);
+        let mut invariant_factors = Vec::new();
+        let mut columns = Vec::with_capacity(size);
+        for (i, factor) in diagonal.into_iter().enumerate() {
+            let degree = factor.degree().unwrap();
+            if degree == 0 {
+                continue;
+            }
+            // Horner's rule over the coefficient vectors of the polynomial vector.
+            let top = (0..size).filter_map(|row| u_inverse[row][i].degree()).max().unwrap_or(0);
+            let mut g = vec![Rational::zero(); size];
+            for k in (0..=top).rev() {
+                g = gen_matrix_vector_mult(self, &g);
+                g.iter_mut().enumerate().for_each(|(row, val)| *val += u_inverse[row][i].coefficient(k));
+            }
+            for _ in 0..degree {
+                let next = gen_matrix_vector_mult(self, &g);
+                columns.push(g);
+                g = next;
+            }
+            invariant_factors.push(factor);
+        }
+        let mut f = Matrix::zeroes(size, size);
+        let mut offset = 0;
+        for factor in invariant_factors.iter() {
+            let companion = factor.companion_matrix();
+            for ((row, col), val) in companion.iter_indexed() {
+                f[(offset + row, offset + col)] = *val;
+            }
+            offset += companion.rows;
+        }
+        RationalCanonicalForm { f, p: Matrix::from_cols(&columns), invariant_factors }
+    }
+
+    // The Jordan form, or None when the characteristic polynomial does not split into linear
+    // factors over the rationals. For each eigenvalue with N = A - lambda * I, the kernels of
+    // N, N^2, ... grow to the generalized eigenspace. Walking down from the top level, a vector
+    // of ker N^j independent of ker N^(j-1) and of the chains started higher up starts a new
+    // chain N^(j-1) * v, ..., N * v, v, which becomes one block of size j.
+    pub fn jordan_form(&self) -> Option<JordanForm> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        let size = self.rows;
+        let eigenvalues = rational_roots(&self.characteristic_polynomial())?;
+        let mut columns = Vec::with_capacity(size);
+        let mut blocks = Vec::new();
+        for (lambda, multiplicity) in eigenvalues {
+            let mut shift = Matrix::identity(size);
+            shift.scale(lambda);
+            let nil = self - &shift;
+            let mut kernels: Vec<Vec<Vec<Rational>>> = vec![Vec::new()];
+            let mut power = Matrix::identity(size);
+            while kernels.last().unwrap().len() < multiplicity {
+                power = gen_matrix_mult(&power, &nil);
+                kernels.push(power.null_space());
+            }
+            let mut chains = Vec::new();
+            // N^(l - j) * v at the current level j for every chain started at a level l above.
+            let mut carried: Vec<Vec<Rational>> = Vec::new();
+            for level in (1..kernels.len()).rev() {
+                let mut spanning = kernels[level - 1].clone();
+                spanning.extend(carried.iter().cloned());
+                let fixed = spanning.len();
+                spanning.extend(kernels[level].iter().cloned());
+                let pivots = Matrix::from_cols(&spanning).reduced_row_echelon_form();
+                for col in pivots.into_iter().filter(|col| *col >= fixed) {
+                    chains.push((spanning[col].clone(), level));
+                    carried.push(spanning[col].clone());
+                }
+                carried = carried.iter().map(|v| gen_matrix_vector_mult(&nil, v)).collect();
+            }
+            for (top, length) in chains {
+                let mut chain = vec![top];
+                for _ in 1..length {
+                    chain.push(gen_matrix_vector_mult(&nil, chain.last().unwrap()));
+                }
+                columns.extend(chain.into_iter().rev());
+                blocks.push((lambda, length));
+            }
+        }
+        let mut j = Matrix::zeroes(size, size);
+        let mut offset = 0;
+        for (lambda, length) in blocks.iter() {
+            for i in 0..*length {
+                j[(offset + i, offset + i)] = *lambda;
+                if i + 1 < *length {
+                    j[(offset + i, offset + i + 1)] = Rational::one();
+                }
+            }
+            offset += length;
+        }
+        Some(JordanForm { j, p: Matrix::from_cols(&columns), blocks })
+    }
+}
+
+// The Smith form of x * I - A over T[x] by Euclid's algorithm on degrees, returning its monic
+// diagonal and U(x)^-1. Row operations on the matrix are mirrored as the inverse column
+// operations on U^-1; column operations need no record.
+fn polynomial_smith_form<T: Number>(a: &Matrix<T>) -> (Vec<Polynomial<T>>, Vec<Vec<Polynomial<T>>>) {
+    let size = a.rows;
+    let mut m: Vec<Vec<Polynomial<T>>> = (0..size).map(|row| (0..size).map(|col| {
+        let entry = Polynomial::constant(-a[(row, col)]);
+        if row == col { entry + Polynomial::x() } else { entry }
+    }).collect()).collect();
+    let mut w: Vec<Vec<Polynomial<T>>> = (0..size).map(|row| (0..size).map(|col| {
+        if row == col { Polynomial::one() } else { Polynomial::zero() }
+    }).collect()).collect();
+    for t in 0..size {
+        // det(x * I - A) is nonzero, so every trailing block has a nonzero entry.
+        let (row, col) = (t..size).flat_map(|row| (t..size).map(move |col| (row, col)))
+            .filter(|(row, col)| !m[*row][*col].is_zero())
+            .min_by_key(|(row, col)| m[*row][*col].degree())
+            .unwrap();
+        swap_rows(&mut m, &mut w, t, row);
+        swap_cols(&mut m, t, col);
+        loop {
+            let pivot = m[t][t].clone();
+            for row in t+1..size {
+                let q = m[row][t].div_rem(&pivot).0;
+                if !q.is_zero() {
+                    add_row_multiple(&mut m, &mut w, row, t, &-q);
+                }
+            }
+            for col in t+1..size {
+                let q = m[t][col].div_rem(&pivot).0;
+                if !q.is_zero() {
+                    add_col_multiple(&mut m, col, t, &-q);
+                }
+            }
+            if let Some(row) = (t+1..size).find(|row| !m[*row][t].is_zero()) {
+                swap_rows(&mut m, &mut w, t, row);
+                continue;
+            }
+            if let Some(col) = (t+1..size).find(|col| !m[t][*col].is_zero()) {
+                swap_cols(&mut m, t, col);
+                continue;
+            }
+            let undivided = (t+1..size).find(|row| (t+1..size).any(|col| !m[*row][col].div_rem(&pivot).1.is_zero()));
+            match undivided {
+                Some(row) => add_row_multiple(&mut m, &mut w, t, row, &Polynomial::one()),
+                None => break,
+            }
+        }
+        let lead = m[t][t].leading_coefficient();
+        for entry in m[t][t..].iter_mut() {
+            *entry = entry.scale(lead.inverse());
+        }
+        for row in w.iter_mut() {
+            row[t] = row[t].scale(lead);
+        }
+    }
+    let diagonal = (0..size).map(|i| m[i][i].clone()).collect();
+    (diagonal, w)
+}
+
+fn swap_rows<T: Number>(m: &mut [Vec<Polynomial<T>>], w: &mut [Vec<Polynomial<T>>], row1: usize, row2: usize) {
+    m.swap(row1, row2);
+    for row in w.iter_mut() {
+        row.swap(row1, row2);
+    }
+}
+
+fn swap_cols<T: Number>(m: &mut [Vec<Polynomial<T>>], col1: usize, col2: usize) {
+    for row in m.iter_mut() {
+        row.swap(col1, col2);
+    }
+}
+
+// Adds factor times the source row to the target row; on U^-1 this subtracts factor times
+// column target from column source.
+fn add_row_multiple<T: Number>(m: &mut [Vec<Polynomial<T>>], w: &mut [Vec<Polynomial<T>>], target: usize, source: usize, factor: &Polynomial<T>) {
+    let added: Vec<Polynomial<T>> = m[source].iter().map(|entry| factor * entry).collect();
+    for (entry, product) in m[target].iter_mut().zip(added.iter()) {
+        *entry = &*entry + product;
+    }
+    for row in w.iter_mut() {
+        let product = factor * &row[target];
+        row[source] = &row[source] - &product;
+    }
+}
+
+fn add_col_multiple<T: Number>(m: &mut [Vec<Polynomial<T>>], target: usize, source: usize, factor: &Polynomial<T>) {
+    for row in m.iter_mut() {
+        let product = factor * &row[source];
+        row[target] = &row[target] + &product;
+    }
+}
+
+// The rational roots with multiplicity when p splits over the rationals, found exactly by the
+// rational root theorem. Scaled to coprime integer coefficients a_0, ..., a_m with the roots at
+// zero factored out, every root n / d in lowest terms has n dividing a_0 and d dividing a_m,
+// and only such candidates are tried. Each is divided out by d * x - n, which by Gauss's
+// lemma leaves integer coefficients exactly when n / d is a root. Also None when the integer
+// arithmetic overflows i128. Finding the divisors takes trial division up to the square root
+// of a_0 and a_m.
+fn rational_roots(p: &Polynomial<Rational>) -> Option<Vec<(Rational, usize)>> {
+    let mut coeffs = integer_coefficients(p)?;
+    let mut roots = Vec::new();
+    let zeros = coeffs.iter().take_while(|c| **c == 0).count();
+    if zeros > 0 {
+        roots.push((Rational::zero(), zeros));
+        coeffs.drain(..zeros);
+    }
+    let numerators = divisors(coeffs[0].unsigned_abs());
+    let denominators = divisors(coeffs[coeffs.len() - 1].unsigned_abs());
+    for num in numerators.iter().copied() {
+        for den in denominators.iter().copied() {
+            // Roots too large for Rational are left in coeffs, failing the split below.
+            if gcd(num, den) != 1 || num > u64::MAX as u128 || den > u64::MAX as u128 {
+                continue;
+            }
+            for pos in [true, false] {
+                let n = if pos { num as i128 } else { -(num as i128) };
+                let mut multiplicity = 0;
+                while coeffs.len() > 1 {
+                    match divide_linear(&coeffs, n, den as i128)? {
+                        Some(quotient) => coeffs = quotient,
+                        None => break,
+                    }
+                    multiplicity += 1;
+                }
+                if multiplicity > 0 {
+                    roots.push((Rational { pos, num: num as u64, den: den as u64 }, multiplicity));
+                }
+            }
+        }
+    }
+    if coeffs.len() != 1 {
+        return None;
+    }
+    roots.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    Some(roots)
+}
+
+// The coefficients of p scaled by the lcm of their denominators and divided by their gcd,
+// lowest degree first, or None when they do not fit in i128.
+fn integer_coefficients(p: &Polynomial<Rational>) -> Option<Vec<i128>> {
+    let scale = p.coeffs().iter().try_fold(1u128, |acc, c| (acc / gcd(acc, c.den as u128)).checked_mul(c.den as u128))?;
+    let coeffs = p.coeffs().iter().map(|c| {
+        let magnitude = i128::try_from((scale / c.den as u128).checked_mul(c.num as u128)?).ok()?;
+        Some(if c.pos { magnitude } else { -magnitude })
+    }).collect::<Option<Vec<i128>>>()?;
+    let content = coeffs.iter().fold(0, |acc, c| gcd(acc, c.unsigned_abs())) as i128;
+    Some(coeffs.iter().map(|c| c / content).collect())
+}
+
+// The quotient of a_m * x^m + ... + a_0 by d * x - n when it has integer coefficients b_k,
+// found from the top by b_(m-1) = a_m / d and b_(k-1) = (a_k + n * b_k) / d, which leaves
+// a_0 + n * b_0 = 0 as the remainder check. None on overflow, and Some(None) when n / d is
+// not a root.
+fn divide_linear(coeffs: &[i128], n: i128, d: i128) -> Option<Option<Vec<i128>>> {
+    let degree = coeffs.len() - 1;
+    let mut quotient = vec![0; degree];
+    let mut carry = coeffs[degree];
+    for k in (0..degree).rev() {
+        if carry % d != 0 {
+            return Some(None);
+        }
+        quotient[k] = carry / d;
+        carry = coeffs[k].checked_add(n.checked_mul(quotient[k])?)?;
+    }
+    Some(if carry == 0 { Some(quotient) } else { None })
+}
+
+// The positive divisors of a nonzero a.
+fn divisors(a: u128) -> Vec<u128> {
+    let mut found = Vec::new();
+    let mut i = 1;
+    while i <= a / i {
+        if a.is_multiple_of(i) {
+            found.push(i);
+            if i != a / i {
+                found.push(a / i);
+            }
+        }
+        i += 1;
+    }
+    found
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
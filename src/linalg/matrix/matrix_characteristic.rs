@@ -0,0 +1,68 @@
+use crate::{core::number::{Number, Ring}, linalg::{matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult, matrix_row_reduction::magnitude}, polynomial::polynomial::Polynomial}};
+
+impl<T: Ring> Matrix<T> {
+
+    // det(x * I - A) by Berkowitz's algorithm, which never divides and so is exact over
+    // integers as well as fields, in O(n^4) operations.
+    //
+    // Splitting the leading (k+1) x (k+1) block as [[M, C], [R, a]] with M the leading k x k
+    // block, its characteristic polynomial is T * p, where p belongs to M and T is the lower
+    // triangular Toeplitz matrix with first column 1, -a, -R*C, -R*M*C, ..., -R*M^(k-1)*C.
+    pub fn characteristic_polynomial(&self) -> Polynomial<T> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        // Highest degree first while building.
+        let mut p = vec![T::one()];
+        for k in 0..self.rows {
+            let mut toeplitz = Vec::with_capacity(k + 2);
+            toeplitz.push(T::one());
+            toeplitz.push(-self[(k, k)]);
+            let mut v: Vec<T> = (0..k).map(|row| self[(row, k)]).collect();
+            for _ in 0..k {
+                let rv = (0..k).fold(T::zero(), |acc, col| acc + self[(k, col)] * v[col]);
+                toeplitz.push(-rv);
+                v = (0..k).map(|row| (0..k).fold(T::zero(), |acc, col| acc + self[(row, col)] * v[col])).collect();
+            }
+            p = (0..k + 2).map(|i| {
+                (0..=i.min(k)).fold(T::zero(), |acc, j| acc + toeplitz[i - j] * p[j])
+            }).collect();
+        }
+        Polynomial::from_highest(&p)
+    }
+
+    // Whether p(A) = 0 for the characteristic polynomial p, compared exactly.
+    pub fn satisfies_cayley_hamilton(&self) -> bool {
+        self.characteristic_polynomial().eval_matrix(self).data.iter().all(|val| *val == T::zero())
+    }
+}
+
+impl<T: Number> Matrix<T> {
+
+    pub fn satisfies_cayley_hamilton_tol(&self, tol: T) -> bool {
+        self.characteristic_polynomial().eval_matrix(self).data.iter().all(|val| magnitude(*val) <= tol)
+    }
+
+    // The monic m of least degree with m(A) = 0, read off the first linear dependency among
+    // I, A, A^2, ... flattened to vectors. It divides the characteristic polynomial, and
+    // finding the dependency takes exact rank decisions, so this is meant for Rational and ModP.
+    pub fn minimal_polynomial(&self) -> Polynomial<T> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        if self.rows == 0 {
+            return Polynomial::one();
+        }
+        let mut power = Matrix::identity(self.rows);
+        let mut powers = vec![power.data.clone()];
+        for _ in 0..self.rows {
+            power = gen_matrix_mult(&power, self);
+            powers.push(power.data.clone());
+            // The earlier powers are independent, so a dependency is unique up to scale and
+            // involves the newest power.
+            if let Some(dependency) = Matrix::from_cols(&powers).null_space().first() {
+                return Polynomial::new(dependency.clone()).monic();
+            }
+        }
+        // Cayley-Hamilton guarantees a dependency by A^n; only round-off can get here.
+        self.characteristic_polynomial()
+    }
+}
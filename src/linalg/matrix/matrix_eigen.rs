@@ -0,0 +1,270 @@
+use crate::{core::{complex::Complex, number::{int, RealNumber}}, linalg::matrix::{matrix::Matrix, matrix_norm::machine_epsilon}};
+
+// Iterations allowed per eigenvalue before the QR iteration gives up, with exceptional
+// shifts at the 10th and 20th to break cycles.
+const QR_MAX_ITERATIONS: usize = 30;
+
+impl<T: RealNumber> Matrix<T> {
+
+    // An upper Hessenberg matrix similar to A, zero below the first subdiagonal, by
+    // Householder reflections.
+    pub fn hessenberg(&self) -> Matrix<T> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        let mut h = self.clone();
+        reduce_to_hessenberg(&mut h);
+        h
+    }
+
+    // All eigenvalues of a square real matrix, complex conjugate pairs adjacent with the
+    // positive imaginary part second. Balances A, reduces it to Hessenberg form and runs the
+    // Francis double shift QR iteration, which keeps the arithmetic real. None if the
+    // iteration fails to converge.
+    pub fn eigenvalues(&self) -> Option<Vec<Complex<T>>> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        let mut h = self.clone();
+        balance(&mut h);
+        reduce_to_hessenberg(&mut h);
+        hessenberg_eigenvalues(&mut h)
+    }
+}
+
+// Rescales rows and columns by powers of two, an exact similarity, until each row and its
+// column have comparable norms. Companion matrices in particular have wildly unbalanced entries.
+fn balance<T: RealNumber>(a: &mut Matrix<T>) {
+    let n = a.rows;
+    let radix = int::<T>(2);
+    let radix_squared = radix * radix;
+    let threshold = int::<T>(19) / int::<T>(20);
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let mut row_norm = T::zero();
+            let mut col_norm = T::zero();
+            for j in (0..n).filter(|j| *j != i) {
+                row_norm += a[(i, j)].abs();
+                col_norm += a[(j, i)].abs();
+            }
+            if row_norm == T::zero() || col_norm == T::zero() {
+                continue;
+            }
+            let total = row_norm + col_norm;
+            let mut f = T::one();
+            let low = row_norm / radix;
+            while col_norm < low {
+                f *= radix;
+                col_norm *= radix_squared;
+            }
+            let high = row_norm * radix;
+            while col_norm > high {
+                f /= radix;
+                col_norm /= radix_squared;
+            }
+            if (col_norm + row_norm) / f < threshold * total {
+                done = false;
+                let g = T::one() / f;
+                for j in 0..n {
+                    a[(i, j)] *= g;
+                    a[(j, i)] *= f;
+                }
+            }
+        }
+    }
+}
+
+fn reduce_to_hessenberg<T: RealNumber>(a: &mut Matrix<T>) {
+    let n = a.rows;
+    let two = int::<T>(2);
+    let mut v = vec![T::zero(); n];
+    for k in 0..n.saturating_sub(2) {
+        let norm = (k+1..n).fold(T::zero(), |acc, row| acc + a[(row, k)] * a[(row, k)]).sqrt();
+        if norm == T::zero() {
+            continue;
+        }
+        let head = a[(k+1, k)];
+        let alpha = if head > T::zero() { -norm } else { norm };
+        let mut v_norm = T::zero();
+        for row in k+1..n {
+            v[row] = if row == k+1 { a[(row, k)] - alpha } else { a[(row, k)] };
+            v_norm += v[row] * v[row];
+        }
+        if v_norm == T::zero() {
+            continue;
+        }
+        // H * A with H = I - 2 v v^T / (v^T v), touching rows k+1.. only.
+        for col in k..n {
+            let dot = (k+1..n).fold(T::zero(), |acc, row| acc + v[row] * a[(row, col)]);
+            let scale = two * dot / v_norm;
+            for row in k+1..n {
+                a[(row, col)] -= scale * v[row];
+            }
+        }
+        // (H * A) * H, touching columns k+1.. only.
+        for row in 0..n {
+            let dot = (k+1..n).fold(T::zero(), |acc, col| acc + a[(row, col)] * v[col]);
+            let scale = two * dot / v_norm;
+            for col in k+1..n {
+                a[(row, col)] -= scale * v[col];
+            }
+        }
+        for row in k+2..n {
+            a[(row, k)] = T::zero();
+        }
+    }
+}
+
+// The Francis double shift QR iteration on an upper Hessenberg matrix, following EISPACK's
+// hqr. Each sweep deflates from the bottom whenever a subdiagonal entry becomes negligible,
+// splitting off a 1 x 1 block (a real eigenvalue) or a 2 x 2 block (a real or conjugate pair).
+fn hessenberg_eigenvalues<T: RealNumber>(a: &mut Matrix<T>) -> Option<Vec<Complex<T>>> {
+    let n = a.rows;
+    let zero = T::zero();
+    let eps = machine_epsilon::<T>();
+    let half = T::one() / int::<T>(2);
+    let mut values = vec![Complex { re: zero, im: zero }; n];
+    let norm = a.iter_indexed().filter(|((row, col), _)| *row <= *col + 1).fold(zero, |acc, (_, val)| acc + val.abs());
+    // Accumulated exceptional shifts.
+    let mut shift = zero;
+    let mut nn = n;
+    while nn > 0 {
+        let last = nn - 1;
+        let mut iterations = 0;
+        loop {
+            // The lowest l with a negligible subdiagonal entry at (l, l - 1) starts the
+            // unreduced block.
+            let mut l = last;
+            while l > 0 {
+                let mut s = a[(l-1, l-1)].abs() + a[(l, l)].abs();
+                if s == zero {
+                    s = norm;
+                }
+                if a[(l, l-1)].abs() <= eps * s {
+                    a[(l, l-1)] = zero;
+                    break;
+                }
+                l -= 1;
+            }
+            let mut x = a[(last, last)];
+            if l == last {
+                values[last] = Complex { re: x + shift, im: zero };
+                nn -= 1;
+                break;
+            }
+            let mut y = a[(last-1, last-1)];
+            let mut w = a[(last, last-1)] * a[(last-1, last)];
+            if l + 1 == last {
+                let p = half * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += shift;
+                if q >= zero {
+                    let z = p + if p >= zero { z } else { -z };
+                    values[last-1] = Complex { re: x + z, im: zero };
+                    values[last] = Complex { re: if z == zero { x + z } else { x - w / z }, im: zero };
+                } else {
+                    values[last-1] = Complex { re: x + p, im: -z };
+                    values[last] = Complex { re: x + p, im: z };
+                }
+                nn -= 2;
+                break;
+            }
+            if iterations == QR_MAX_ITERATIONS {
+                return None;
+            }
+            if iterations == 10 || iterations == 20 {
+                shift += x;
+                for i in 0..=last {
+                    a[(i, i)] -= x;
+                }
+                let s = a[(last, last-1)].abs() + a[(last-1, last-2)].abs();
+                x = int::<T>(3) / int::<T>(4) * s;
+                y = x;
+                w = -(int::<T>(7) / int::<T>(16)) * s * s;
+            }
+            iterations += 1;
+            // Start the double step at the lowest m where two consecutive small subdiagonal
+            // entries make the implicit shift's bulge negligible above it.
+            let mut m = last - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = a[(m, m)];
+                let rr = x - z;
+                let s = y - z;
+                p = (rr * s - w) / a[(m+1, m)] + a[(m, m+1)];
+                q = a[(m+1, m+1)] - z - rr - s;
+                r = a[(m+2, m+1)];
+                let scale = p.abs() + q.abs() + r.abs();
+                p /= scale;
+                q /= scale;
+                r /= scale;
+                if m == l {
+                    break;
+                }
+                let u = a[(m, m-1)].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[(m-1, m-1)].abs() + z.abs() + a[(m+1, m+1)].abs());
+                if u <= eps * v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m+2..=last {
+                a[(i, i-2)] = zero;
+                if i != m+2 {
+                    a[(i, i-3)] = zero;
+                }
+            }
+            // Chase the bulge down with 3 x 3 Householder reflections.
+            let mut x = zero;
+            for k in m..last {
+                if k != m {
+                    p = a[(k, k-1)];
+                    q = a[(k+1, k-1)];
+                    r = if k + 1 != last { a[(k+2, k-1)] } else { zero };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != zero {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let norm = (p * p + q * q + r * r).sqrt();
+                let s = if p >= zero { norm } else { -norm };
+                if s == zero {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[(k, k-1)] = -a[(k, k-1)];
+                    }
+                } else {
+                    a[(k, k-1)] = -s * x;
+                }
+                p += s;
+                let (hx, hy, hz) = (p / s, q / s, r / s);
+                q /= p;
+                r /= p;
+                for j in k..=last {
+                    let mut t = a[(k, j)] + q * a[(k+1, j)];
+                    if k + 1 != last {
+                        t += r * a[(k+2, j)];
+                        a[(k+2, j)] -= t * hz;
+                    }
+                    a[(k+1, j)] -= t * hy;
+                    a[(k, j)] -= t * hx;
+                }
+                for i in l..=last.min(k + 3) {
+                    let mut t = hx * a[(i, k)] + hy * a[(i, k+1)];
+                    if k + 1 != last {
+                        t += hz * a[(i, k+2)];
+                        a[(i, k+2)] -= t * r;
+                    }
+                    a[(i, k+1)] -= t * q;
+                    a[(i, k)] -= t;
+                }
+            }
+        }
+    }
+    Some(values)
+}
@@ -0,0 +1,133 @@
+use std::fmt::{self, Display};
+
+use crate::{core::number::{Number, Ring}, linalg::matrix::matrix::Matrix};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowOp<T: Ring> {
+    Swap(usize, usize),
+    Scale(usize, T),
+    // AddMultiple(target, source, factor) adds factor times the source row to the target row.
+    AddMultiple(usize, usize, T),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColOp<T: Ring> {
+    Swap(usize, usize),
+    Scale(usize, T),
+    // AddMultiple(target, source, factor) adds factor times the source column to the target column.
+    AddMultiple(usize, usize, T),
+}
+
+impl<T: Ring> RowOp<T> {
+
+    pub fn apply(&self, matrix: &mut Matrix<T>) {
+        match *self {
+            RowOp::Swap(row1, row2) => matrix.swap_rows(row1, row2),
+            RowOp::Scale(row, factor) => matrix.scale_row(row, factor),
+            RowOp::AddMultiple(target, source, factor) => matrix.add_row_multiple(target, source, factor),
+        }
+    }
+
+    // The matrix E with E * A equal to applying the operation to A.
+    pub fn elementary_matrix(&self, size: usize) -> Matrix<T> {
+        let mut m = Matrix::identity(size);
+        self.apply(&mut m);
+        m
+    }
+
+    // The product E_k * ... * E_1 of the elementary matrices of ops applied in order.
+    // A sequence of swaps gives the corresponding permutation matrix.
+    pub fn sequence_matrix(ops: &[RowOp<T>], size: usize) -> Matrix<T> {
+        let mut m = Matrix::identity(size);
+        for op in ops.iter() {
+            op.apply(&mut m);
+        }
+        m
+    }
+}
+
+impl<T: Number> RowOp<T> {
+
+    pub fn inverse(&self) -> RowOp<T> {
+        match *self {
+            RowOp::Swap(row1, row2) => RowOp::Swap(row1, row2),
+            RowOp::Scale(row, factor) => RowOp::Scale(row, factor.inverse()),
+            RowOp::AddMultiple(target, source, factor) => RowOp::AddMultiple(target, source, -factor),
+        }
+    }
+}
+
+impl<T: Ring> ColOp<T> {
+
+    pub fn apply(&self, matrix: &mut Matrix<T>) {
+        match *self {
+            ColOp::Swap(col1, col2) => matrix.swap_cols(col1, col2),
+            ColOp::Scale(col, factor) => matrix.scale_col(col, factor),
+            ColOp::AddMultiple(target, source, factor) => matrix.add_col_multiple(target, source, factor),
+        }
+    }
+
+    // The matrix E with A * E equal to applying the operation to A.
+    pub fn elementary_matrix(&self, size: usize) -> Matrix<T> {
+        let mut m = Matrix::identity(size);
+        self.apply(&mut m);
+        m
+    }
+
+    // The product E_1 * ... * E_k of the elementary matrices of ops applied in order.
+    pub fn sequence_matrix(ops: &[ColOp<T>], size: usize) -> Matrix<T> {
+        let mut m = Matrix::identity(size);
+        for op in ops.iter() {
+            op.apply(&mut m);
+        }
+        m
+    }
+}
+
+impl<T: Number> ColOp<T> {
+
+    pub fn inverse(&self) -> ColOp<T> {
+        match *self {
+            ColOp::Swap(col1, col2) => ColOp::Swap(col1, col2),
+            ColOp::Scale(col, factor) => ColOp::Scale(col, factor.inverse()),
+            ColOp::AddMultiple(target, source, factor) => ColOp::AddMultiple(target, source, -factor),
+        }
+    }
+}
+
+impl<T: Ring + Display> RowOp<T> {
+
+    pub fn to_latex(&self) -> String {
+        match self {
+            RowOp::Swap(row1, row2) => format!("R_{{{}}} \\leftrightarrow R_{{{}}}", row1 + 1, row2 + 1),
+            RowOp::Scale(row, factor) => format!("R_{{{}}} \\to ({}) R_{{{}}}", row + 1, factor, row + 1),
+            RowOp::AddMultiple(target, source, factor) => {
+                format!("R_{{{}}} \\to R_{{{}}} + ({}) R_{{{}}}", target + 1, target + 1, factor, source + 1)
+            }
+        }
+    }
+}
+
+impl<T: Ring + Display> fmt::Display for RowOp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowOp::Swap(row1, row2) => write!(f, "R{} <-> R{}", row1 + 1, row2 + 1),
+            RowOp::Scale(row, factor) => write!(f, "R{} -> ({})R{}", row + 1, factor, row + 1),
+            RowOp::AddMultiple(target, source, factor) => {
+                write!(f, "R{} -> R{} + ({})R{}", target + 1, target + 1, factor, source + 1)
+            }
+        }
+    }
+}
+
+impl<T: Ring + Display> fmt::Display for ColOp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColOp::Swap(col1, col2) => write!(f, "C{} <-> C{}", col1 + 1, col2 + 1),
+            ColOp::Scale(col, factor) => write!(f, "C{} -> ({})C{}", col + 1, factor, col + 1),
+            ColOp::AddMultiple(target, source, factor) => {
+                write!(f, "C{} -> C{} + ({})C{}", target + 1, target + 1, factor, source + 1)
+            }
+        }
+    }
+}
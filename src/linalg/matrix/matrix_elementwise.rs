@@ -0,0 +1,60 @@
+use crate::{core::number::Ring, linalg::{matrix::matrix::Matrix, vector::vector_elementwise::{gen_vector_argmax, gen_vector_argmin}}};
+
+impl<T: Ring> Matrix<T> {
+
+    pub fn map<U: Ring, F: FnMut(T) -> U>(&self, f: F) -> Matrix<U> {
+        Matrix { rows: self.rows, cols: self.cols, data: self.data.iter().copied().map(f).collect() }
+    }
+
+    pub fn map_inplace<F: FnMut(T) -> T>(&mut self, mut f: F) {
+        for val in self.data.iter_mut() {
+            *val = f(*val);
+        }
+    }
+
+    pub fn zip_map<U: Ring, R: Ring, F: FnMut(T, U) -> R>(&self, other: &Matrix<U>, mut f: F) -> Matrix<R> {
+        assert_eq!(self.shape(), other.shape());
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().zip(other.data.iter()).map(|(a, b)| f(*a, *b)).collect(),
+        }
+    }
+
+    pub fn zip_apply<U: Ring, F: FnMut(T, U) -> T>(&mut self, other: &Matrix<U>, mut f: F) {
+        assert_eq!(self.shape(), other.shape());
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a = f(*a, *b);
+        }
+    }
+
+    pub fn fold<A, F: FnMut(A, T) -> A>(&self, init: A, f: F) -> A {
+        self.data.iter().copied().fold(init, f)
+    }
+
+    pub fn sum(&self) -> T {
+        self.fold(T::zero(), |acc, val| acc + val)
+    }
+
+    pub fn product(&self) -> T {
+        self.fold(T::one(), |acc, val| acc * val)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.argmin().map(|loc| self[loc])
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.argmax().map(|loc| self[loc])
+    }
+
+    // Location of the first smallest element.
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        gen_vector_argmin(&self.data).map(|i| self.index_to_location(i))
+    }
+
+    // Location of the first largest element.
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        gen_vector_argmax(&self.data).map(|i| self.index_to_location(i))
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::{Display, Write};
+
+use crate::{core::number::Number, linalg::matrix::{matrix::Matrix, matrix_row_reduction::magnitude_greater}};
+
+pub use crate::linalg::matrix::matrix_elementary::RowOp;
+
+// The operations performed by an elimination and the matrix after each of them.
+pub struct EliminationTrace<T: Number> {
+    pub initial: Matrix<T>,
+    pub steps: Vec<(RowOp<T>, Matrix<T>)>,
+}
+
+impl<T: Number> EliminationTrace<T> {
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn ops(&self) -> Vec<RowOp<T>> {
+        self.steps.iter().map(|(op, _)| *op).collect()
+    }
+
+    pub fn result(&self) -> &Matrix<T> {
+        self.steps.last().map_or(&self.initial, |(_, state)| state)
+    }
+
+    // The matrix after the first step operations.
+    pub fn state(&self, step: usize) -> &Matrix<T> {
+        assert!(step <= self.steps.len());
+        if step == 0 { &self.initial } else { &self.steps[step - 1].1 }
+    }
+
+    // Moves a matrix from the state before step to the state after it.
+    pub fn step_forward(&self, matrix: &mut Matrix<T>, step: usize) {
+        self.steps[step].0.apply(matrix);
+    }
+
+    // Moves a matrix from the state after step back to the state before it.
+    pub fn step_backward(&self, matrix: &mut Matrix<T>, step: usize) {
+        self.steps[step].0.inverse().apply(matrix);
+    }
+
+    pub fn replay_forward(&self) -> Matrix<T> {
+        let mut matrix = self.initial.clone();
+        for step in 0..self.steps.len() {
+            self.step_forward(&mut matrix, step);
+        }
+        matrix
+    }
+
+    pub fn replay_backward(&self) -> Matrix<T> {
+        let mut matrix = self.result().clone();
+        for step in (0..self.steps.len()).rev() {
+            self.step_backward(&mut matrix, step);
+        }
+        matrix
+    }
+}
+
+impl<T: Number + Display> EliminationTrace<T> {
+
+    pub fn to_text(&self) -> String {
+        let mut out = format!("{}\n", self.initial);
+        for (op, state) in self.steps.iter() {
+            let _ = write!(out, "\n{}\n{}\n", op, state);
+        }
+        out
+    }
+
+    pub fn to_latex(&self) -> String {
+        let mut out = String::from("\\begin{align*}\n");
+        let _ = write!(out, "& {}", latex_matrix(&self.initial));
+        for (op, state) in self.steps.iter() {
+            let _ = write!(out, " \\\\\n\\xrightarrow{{{}}} & {}", op.to_latex(), latex_matrix(state));
+        }
+        out.push_str("\n\\end{align*}\n");
+        out
+    }
+}
+
+fn latex_matrix<T: Number + Display>(matrix: &Matrix<T>) -> String {
+    let mut out = String::from("\\begin{bmatrix}");
+    for row in 0..matrix.rows {
+        if row > 0 {
+            out.push_str(" \\\\");
+        }
+        for col in 0..matrix.cols {
+            if col > 0 {
+                out.push_str(" &");
+            }
+            let _ = write!(out, " {}", matrix[(row, col)]);
+        }
+    }
+    out.push_str(" \\end{bmatrix}");
+    out
+}
+
+impl<T: Number> Matrix<T> {
+
+    // Computes the same reduced row echelon form as reduced_row_echelon_form, recording
+    // each elementary row operation and the matrix it produced. Pivots and eliminated entries
+    // are tested against exact zero, so this is meant for exact types such as Rational and
+    // ModP. Over floats, round-off leaves tiny entries that get recorded as pivots, and
+    // reduced_row_echelon_form_tol should be used instead.
+    pub fn reduced_row_echelon_form_traced(&self) -> EliminationTrace<T> {
+        assert!(self.well_formed());
+        let mut trace = EliminationTrace { initial: self.clone(), steps: Vec::new() };
+        let mut current = self.clone();
+        let mut record = |op: RowOp<T>, current: &mut Matrix<T>| {
+            op.apply(current);
+            trace.steps.push((op, current.clone()));
+        };
+        let mut start_row = 0;
+        let mut start_col = 0;
+        while start_row < self.rows && start_col < self.cols {
+            let mut max_index = start_row;
+            let mut max_val = current[(start_row, start_col)];
+            for row in start_row+1..self.rows {
+                let test_val = current[(row, start_col)];
+                if magnitude_greater(test_val, max_val) {
+                    max_index = row;
+                    max_val = test_val;
+                }
+            }
+            if max_val != T::zero() {
+                if max_index != start_row {
+                    record(RowOp::Swap(start_row, max_index), &mut current);
+                }
+                if max_val != T::one() {
+                    record(RowOp::Scale(start_row, max_val.inverse()), &mut current);
+                }
+                for row in 0..self.rows {
+                    let val = current[(row, start_col)];
+                    if row != start_row && val != T::zero() {
+                        record(RowOp::AddMultiple(row, start_row, -val), &mut current);
+                    }
+                }
+                start_row += 1;
+            }
+            start_col += 1;
+        }
+        trace
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{core::{complex::Complex, number::{Number, Ring}}, linalg::matrix::{matrix::Matrix, matrix_lu::LuDecomposition, matrix_mult::gen_matrix_mult, matrix_scaler_mult::gen_matrix_scaler_mult}};
+
+// Padé degrees with the largest 1-norm each reaches double precision at, from Higham,
+// "The Scaling and Squaring Method for the Matrix Exponential Revisited".
+const EXPM_PADE_THETA: [(usize, f64); 5] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068),
+    (13, 5.371920351148152),
+];
+
+// log(I + X) by the degree 7 Padé approximant is accurate to double precision for ||X||_1 up to this.
+const LOGM_PADE_DEGREE: usize = 7;
+const LOGM_PADE_THETA: f64 = 2.64e-1;
+const LOGM_MAX_SQUARE_ROOTS: usize = 64;
+
+const SQRTM_MAX_STEPS: usize = 100;
+// Relative changes below which determinant scaling is dropped and stagnation ends the iteration.
+const SQRTM_SCALING_CUTOFF: f64 = 1e-2;
+const SQRTM_STAGNATION: f64 = 1e-6;
+
+impl<T: Ring> Matrix<T> {
+
+    // A^n by repeated squaring, in at most 2 * log2(n) products. A^0 is the identity.
+    pub fn pow(&self, n: u64) -> Matrix<T> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = gen_matrix_mult(&result, &base);
+            }
+            n >>= 1;
+            if n > 0 {
+                base = gen_matrix_mult(&base, &base);
+            }
+        }
+        result
+    }
+}
+
+impl Matrix<f64> {
+
+    // None when round-off leaves the Padé denominator q(A) singular.
+    pub fn expm(&self) -> Option<Matrix<f64>> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        expm(self)
+    }
+
+    // The principal logarithm, or None when A has an eigenvalue on the closed negative real
+    // axis, where the principal branch is undefined.
+    pub fn logm(&self) -> Option<Matrix<f64>> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        logm(self)
+    }
+
+    // The principal square root, with the same conditions as logm.
+    pub fn sqrtm(&self) -> Option<Matrix<f64>> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        sqrtm(self)
+    }
+}
+
+impl Matrix<Complex<f64>> {
+
+    // None when round-off leaves the Padé denominator q(A) singular.
+    pub fn expm(&self) -> Option<Matrix<Complex<f64>>> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        expm(self)
+    }
+
+    // The principal logarithm, or None when A has an eigenvalue on the closed negative real
+    // axis, where the principal branch is undefined.
+    pub fn logm(&self) -> Option<Matrix<Complex<f64>>> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        logm(self)
+    }
+
+    // The principal square root, with the same conditions as logm. The iteration converges
+    // to the principal root whenever it exists and wanders otherwise, so no other root of
+    // an eigenvalue on or near the negative real axis is ever returned.
+    pub fn sqrtm(&self) -> Option<Matrix<Complex<f64>>> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        sqrtm(self)
+    }
+}
+
+// The scalars the matrix functions are defined over.
+trait FunctionScalar: Number {
+    fn from_f64(val: f64) -> Self;
+    fn modulus(self) -> f64;
+}
+
+impl FunctionScalar for f64 {
+    fn from_f64(val: f64) -> Self { val }
+    fn modulus(self) -> f64 { self.abs() }
+}
+
+impl FunctionScalar for Complex<f64> {
+    fn from_f64(val: f64) -> Self { Complex { re: val, im: 0.0 } }
+    fn modulus(self) -> f64 { self.abs() }
+}
+
+// Scales A by 2^-s until its norm is within reach of a Padé approximant, then squares
+// the approximant s times.
+fn expm<T: FunctionScalar>(a: &Matrix<T>) -> Option<Matrix<T>> {
+    let norm = norm_1(a);
+    let (degree, theta) = EXPM_PADE_THETA.iter().copied()
+        .find(|(_, theta)| norm <= *theta)
+        .unwrap_or(EXPM_PADE_THETA[EXPM_PADE_THETA.len() - 1]);
+    let squarings = if norm > theta { (norm / theta).log2().ceil() as i32 } else { 0 };
+    let scaled = gen_matrix_scaler_mult(T::from_f64(0.5f64.powi(squarings)), a);
+    let mut result = pade_exp(&scaled, degree)?;
+    for _ in 0..squarings {
+        result = gen_matrix_mult(&result, &result);
+    }
+    Some(result)
+}
+
+// The [m/m] Padé approximant q(A)^-1 * p(A), where p(x) = sum of b_j x^j and q(x) = p(-x).
+// Splitting p into its even part V and odd part U gives p(A) = V + U and q(A) = V - U.
+fn pade_exp<T: FunctionScalar>(a: &Matrix<T>, degree: usize) -> Option<Matrix<T>> {
+    let size = a.rows;
+    let a2 = gen_matrix_mult(a, a);
+    let mut even = Matrix::zeroes(size, size);
+    let mut odd = Matrix::zeroes(size, size);
+    // A^j for even j and A^(j-1) for odd j, so the odd part picks up its last factor of A once.
+    let mut power = Matrix::identity(size);
+    let mut coeff = 1.0;
+    for j in 0..=degree {
+        if j % 2 == 0 {
+            if j > 0 {
+                power = gen_matrix_mult(&power, &a2);
+            }
+            even += gen_matrix_scaler_mult(T::from_f64(coeff), &power);
+        } else {
+            odd += gen_matrix_scaler_mult(T::from_f64(coeff), &power);
+        }
+        coeff *= (degree - j) as f64 / ((j + 1) * (2 * degree - j)) as f64;
+    }
+    let odd = gen_matrix_mult(a, &odd);
+    // q(A) is well conditioned for every norm within the Padé thresholds, but round-off can
+    // still leave an exactly zero pivot.
+    solve_columns(&(&even - &odd).lu(), &(&even + &odd))
+}
+
+// Takes square roots until A is close to I, approximates log(I + X) there and undoes the
+// roots with log(A) = 2^k * log(A^(1/2^k)).
+fn logm<T: FunctionScalar>(a: &Matrix<T>) -> Option<Matrix<T>> {
+    let size = a.rows;
+    let identity = Matrix::identity(size);
+    let mut root = a.clone();
+    let mut square_roots = 0;
+    while norm_1(&(&root - &identity)) > LOGM_PADE_THETA {
+        if square_roots == LOGM_MAX_SQUARE_ROOTS {
+            return None;
+        }
+        root = sqrtm(&root)?;
+        square_roots += 1;
+    }
+    // Gauss-Legendre quadrature of log(I + X) = integral over [0, 1] of X * (I + t * X)^-1 dt
+    // with m nodes is exactly the [m/m] Padé approximant.
+    let x = &root - &identity;
+    let mut result = Matrix::zeroes(size, size);
+    for (node, weight) in gauss_legendre(LOGM_PADE_DEGREE) {
+        let shifted = &identity + &gen_matrix_scaler_mult(T::from_f64(node), &x);
+        let term = solve_columns(&shifted.lu(), &x)?;
+        result += gen_matrix_scaler_mult(T::from_f64(weight), &term);
+    }
+    Some(gen_matrix_scaler_mult(T::from_f64(2.0f64.powi(square_roots as i32)), &result))
+}
+
+// The Denman-Beavers iteration Y <- (Y + Z^-1) / 2, Z <- (Z + Y^-1) / 2 from Y = A, Z = I,
+// which converges quadratically to A^(1/2) and A^(-1/2). Scaling both by
+// |det(Y) * det(Z)|^(-1/2n) shortens the slow start for badly scaled A, but near the root it
+// only perturbs the quadratic convergence, so it stops once the relative change is small.
+fn sqrtm<T: FunctionScalar>(a: &Matrix<T>) -> Option<Matrix<T>> {
+    let size = a.rows;
+    let half = T::from_f64(0.5);
+    let mut y = a.clone();
+    let mut z = Matrix::identity(size);
+    let mut last_change = f64::INFINITY;
+    let mut scaling = true;
+    for _ in 0..SQRTM_MAX_STEPS {
+        let y_lu = y.lu();
+        let z_lu = z.lu();
+        let y_inv = y_lu.inverse()?;
+        let z_inv = z_lu.inverse()?;
+        let gamma = if scaling { (-(log_abs_det(&y_lu) + log_abs_det(&z_lu)) / (2 * size) as f64).exp() } else { 1.0 };
+        let next_y = gen_matrix_scaler_mult(half, &(&gen_matrix_scaler_mult(T::from_f64(gamma), &y) + &gen_matrix_scaler_mult(T::from_f64(1.0 / gamma), &z_inv)));
+        let next_z = gen_matrix_scaler_mult(half, &(&gen_matrix_scaler_mult(T::from_f64(gamma), &z) + &gen_matrix_scaler_mult(T::from_f64(1.0 / gamma), &y_inv)));
+        let change = norm_1(&(&next_y - &y)) / norm_1(&next_y);
+        y = next_y;
+        z = next_z;
+        if change <= size as f64 * f64::EPSILON {
+            break;
+        }
+        // Round-off stops the iterates settling exactly, so once the change is small also stop
+        // when it no longer shrinks. Far from the root it can grow for a step or two.
+        if change <= SQRTM_STAGNATION && change >= last_change {
+            break;
+        }
+        if change <= SQRTM_SCALING_CUTOFF {
+            scaling = false;
+        }
+        last_change = change;
+    }
+    // Without a principal root the iteration wanders instead of converging, leaving a residual
+    // far above the round-off an ill-conditioned A can cause.
+    let residual = norm_1(&(&gen_matrix_mult(&y, &y) - a));
+    let y_norm = norm_1(&y);
+    if residual <= f64::EPSILON.sqrt() * y_norm * y_norm {
+        Some(y)
+    } else {
+        None
+    }
+}
+
+// Solves A * X = B one column of B at a time, or None when A is singular.
+fn solve_columns<T: Number>(lu: &LuDecomposition<T>, b: &Matrix<T>) -> Option<Matrix<T>> {
+    let cols = (0..b.cols)
+        .map(|col| lu.solve(&(0..b.rows).map(|row| b[(row, col)]).collect::<Vec<_>>()))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Matrix::from_cols(&cols))
+}
+
+fn norm_1<T: FunctionScalar>(a: &Matrix<T>) -> f64 {
+    (0..a.cols).map(|col| (0..a.rows).map(|row| a[(row, col)].modulus()).sum::<f64>()).fold(0.0, f64::max)
+}
+
+// log |det(A)| from the diagonal of U, which stays finite where det(A) itself would overflow.
+fn log_abs_det<T: FunctionScalar>(lu: &LuDecomposition<T>) -> f64 {
+    lu.lu.diagonal_iter().map(|val| val.modulus().ln()).sum()
+}
+
+// Nodes and weights of the m point Gauss-Legendre rule on [0, 1], found by Newton's method on
+// the Legendre polynomial P_m from the usual cosine estimates of its roots.
+fn gauss_legendre(m: usize) -> Vec<(f64, f64)> {
+    (0..m).map(|i| {
+        let mut x = (std::f64::consts::PI * (i as f64 + 0.75) / (m as f64 + 0.5)).cos();
+        let mut derivative = 1.0;
+        for _ in 0..100 {
+            // P_m(x) and P_m'(x) by the three term recurrence.
+            let (mut p, mut p_prev) = (1.0, 0.0);
+            for k in 1..=m {
+                let k = k as f64;
+                (p, p_prev) = (((2.0 * k - 1.0) * x * p - (k - 1.0) * p_prev) / k, p);
+            }
+            derivative = m as f64 * (x * p - p_prev) / (x * x - 1.0);
+            let step = p / derivative;
+            x -= step;
+            if step.abs() <= f64::EPSILON {
+                break;
+            }
+        }
+        ((1.0 - x) / 2.0, 1.0 / ((1.0 - x * x) * derivative * derivative))
+    }).collect()
+}
@@ -0,0 +1,133 @@
+use crate::{core::number::{Number, Ring}, linalg::{matrix::matrix::Matrix, vector::util::zeroed_vec}};
+
+impl<T: Ring> Matrix<T> {
+    #[inline(always)]
+    pub fn hadamard_mult(&mut self, other: &Matrix<T>) {
+        assert!(self.well_formed());
+        assert!(other.well_formed());
+        assert_eq!(self.shape(), other.shape());
+        unsafe {
+            self.u_hadamard_mult(other, self.size());
+        }
+    }
+
+    #[inline(always)]
+    pub unsafe fn u_hadamard_mult(&mut self, other: &Matrix<T>, size: usize) {
+        for i in 0..size {
+            unsafe {
+                *self.get_mut(i) = *self.get(i) * *other.get(i);
+            }
+        }
+    }
+}
+
+impl<T: Number> Matrix<T> {
+    #[inline(always)]
+    pub fn hadamard_div(&mut self, other: &Matrix<T>) {
+        assert!(self.well_formed());
+        assert!(other.well_formed());
+        assert_eq!(self.shape(), other.shape());
+        unsafe {
+            self.u_hadamard_div(other, self.size());
+        }
+    }
+
+    #[inline(always)]
+    pub unsafe fn u_hadamard_div(&mut self, other: &Matrix<T>, size: usize) {
+        for i in 0..size {
+            unsafe {
+                *self.get_mut(i) = *self.get(i) / *other.get(i);
+            }
+        }
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_hadamard_mult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    assert!(a.well_formed());
+    assert!(b.well_formed());
+    assert_eq!(a.shape(), b.shape());
+    let size = a.size();
+    unsafe {
+        u_gen_matrix_hadamard_mult(a, b, size)
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_hadamard_mult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, size: usize) -> Matrix<T> {
+    unsafe {
+        let mut des = Matrix {
+            rows: a.rows,
+            cols: a.cols,
+            data: zeroed_vec(size),
+        };
+        u_gen_matrix_ihadamard_mult(a, b, &mut des, size);
+        des
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_ihadamard_mult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
+    assert!(a.well_formed());
+    assert!(b.well_formed());
+    assert!(des.well_formed());
+    assert_eq!(a.shape(), b.shape());
+    assert_eq!(a.shape(), des.shape());
+    unsafe {
+        u_gen_matrix_ihadamard_mult(a, b, des, a.size());
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_ihadamard_mult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
+    for i in 0..size {
+        unsafe {
+            *des.get_mut(i) = *a.get(i) * *b.get(i);
+        }
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_hadamard_div<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    assert!(a.well_formed());
+    assert!(b.well_formed());
+    assert_eq!(a.shape(), b.shape());
+    let size = a.size();
+    unsafe {
+        u_gen_matrix_hadamard_div(a, b, size)
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_hadamard_div<T: Number>(a: &Matrix<T>, b: &Matrix<T>, size: usize) -> Matrix<T> {
+    unsafe {
+        let mut des = Matrix {
+            rows: a.rows,
+            cols: a.cols,
+            data: zeroed_vec(size),
+        };
+        u_gen_matrix_ihadamard_div(a, b, &mut des, size);
+        des
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_ihadamard_div<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
+    assert!(a.well_formed());
+    assert!(b.well_formed());
+    assert!(des.well_formed());
+    assert_eq!(a.shape(), b.shape());
+    assert_eq!(a.shape(), des.shape());
+    unsafe {
+        u_gen_matrix_ihadamard_div(a, b, des, a.size());
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_ihadamard_div<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
+    for i in 0..size {
+        unsafe {
+            *des.get_mut(i) = *a.get(i) / *b.get(i);
+        }
+    }
+}
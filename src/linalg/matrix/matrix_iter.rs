@@ -0,0 +1,170 @@
+use std::{marker::PhantomData, ops::{Index, IndexMut}};
+
+use crate::{core::number::Ring, linalg::matrix::matrix::Matrix};
+
+// A column of a row-major matrix, seen through its stride.
+pub struct ColView<'a, T: Ring> {
+    data: &'a [T],
+    col: usize,
+    stride: usize,
+    len: usize,
+}
+
+impl<'a, T: Ring> ColView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a T> + DoubleEndedIterator + use<'a, T> {
+        let data = self.data;
+        let (col, stride) = (self.col, self.stride);
+        (0..self.len).map(move |i| unsafe { data.get_unchecked(i * stride + col) })
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+}
+
+impl<T: Ring> Index<usize> for ColView<'_, T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len);
+        &self.data[index * self.stride + self.col]
+    }
+}
+
+pub struct ColViewMut<'a, T: Ring> {
+    ptr: *mut T,
+    stride: usize,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: Ring> ColViewMut<'a, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator {
+        (0..self.len).map(move |i| unsafe { &*self.ptr.add(i * self.stride) })
+    }
+
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut T> + DoubleEndedIterator {
+        let (ptr, stride) = (self.ptr, self.stride);
+        (0..self.len).map(move |i| unsafe { &mut *ptr.add(i * stride) })
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+}
+
+impl<T: Ring> Index<usize> for ColViewMut<'_, T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.len);
+        unsafe { &*self.ptr.add(index * self.stride) }
+    }
+}
+
+impl<T: Ring> IndexMut<usize> for ColViewMut<'_, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < self.len);
+        unsafe { &mut *self.ptr.add(index * self.stride) }
+    }
+}
+
+impl<T: Ring> Matrix<T> {
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    pub fn rows_iter(&self) -> impl ExactSizeIterator<Item = &[T]> + DoubleEndedIterator {
+        let cols = self.cols;
+        (0..self.rows).map(move |i| &self.data[i * cols..(i + 1) * cols])
+    }
+
+    pub fn rows_iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut [T]> + DoubleEndedIterator {
+        let (rows, cols) = (self.rows, self.cols);
+        let ptr = self.data.as_mut_ptr();
+        assert_eq!(rows * cols, self.data.len());
+        (0..rows).map(move |i| unsafe { std::slice::from_raw_parts_mut(ptr.add(i * cols), cols) })
+    }
+
+    pub fn cols_iter(&self) -> impl ExactSizeIterator<Item = ColView<'_, T>> + DoubleEndedIterator {
+        let (rows, cols) = (self.rows, self.cols);
+        assert_eq!(rows * cols, self.data.len());
+        (0..cols).map(move |col| ColView { data: &self.data, col, stride: cols, len: rows })
+    }
+
+    pub fn cols_iter_mut(&mut self) -> impl ExactSizeIterator<Item = ColViewMut<'_, T>> + DoubleEndedIterator {
+        let (rows, cols) = (self.rows, self.cols);
+        assert_eq!(rows * cols, self.data.len());
+        let ptr = self.data.as_mut_ptr();
+        (0..cols).map(move |col| ColViewMut { ptr: unsafe { ptr.add(col) }, stride: cols, len: rows, _marker: PhantomData })
+    }
+
+    pub fn iter_indexed(&self) -> impl ExactSizeIterator<Item = ((usize, usize), &T)> + DoubleEndedIterator {
+        let cols = self.cols;
+        self.data.iter().enumerate().map(move |(i, val)| ((i / cols, i % cols), val))
+    }
+
+    pub fn iter_indexed_mut(&mut self) -> impl ExactSizeIterator<Item = ((usize, usize), &mut T)> + DoubleEndedIterator {
+        let cols = self.cols;
+        self.data.iter_mut().enumerate().map(move |(i, val)| ((i / cols, i % cols), val))
+    }
+
+    pub fn diagonal_iter(&self) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator {
+        let cols = self.cols;
+        (0..self.rows.min(self.cols)).map(move |i| &self.data[i * cols + i])
+    }
+
+    pub fn diagonal_iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut T> + DoubleEndedIterator {
+        let cols = self.cols;
+        let len = self.rows.min(self.cols);
+        assert!(len == 0 || (len - 1) * cols + len - 1 < self.data.len());
+        let ptr = self.data.as_mut_ptr();
+        (0..len).map(move |i| unsafe { &mut *ptr.add(i * cols + i) })
+    }
+}
+
+impl<T: Ring> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: Ring> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, T: Ring> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
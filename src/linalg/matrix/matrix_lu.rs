@@ -0,0 +1,177 @@
+use crate::{core::number::Number, linalg::{matrix::{matrix::Matrix, matrix_row_reduction::magnitude_greater}, permutation::Permutation}};
+
+// P * A = L * U with L unit lower triangular and U upper triangular, both packed into lu;
+// the unit diagonal of L is not stored.
+pub struct LuDecomposition<T: Number> {
+    pub lu: Matrix<T>,
+    pub perm: Permutation,
+}
+
+impl<T: Number> LuDecomposition<T> {
+
+    pub fn size(&self) -> usize {
+        self.lu.rows
+    }
+
+    pub fn l(&self) -> Matrix<T> {
+        let size = self.size();
+        Matrix::from_fn(size, size, |row, col| {
+            if row == col {
+                T::one()
+            } else if row > col {
+                self.lu[(row, col)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn u(&self) -> Matrix<T> {
+        let size = self.size();
+        Matrix::from_fn(size, size, |row, col| if row <= col { self.lu[(row, col)] } else { T::zero() })
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.lu.diagonal_iter().any(|val| *val == T::zero())
+    }
+
+    pub fn determinant(&self) -> T {
+        self.lu.diagonal_iter().fold(self.perm.sign(), |acc, val| acc * *val)
+    }
+
+    // Solves A * x = b, returning None when A is singular.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        assert_eq!(b.len(), self.size());
+        if self.is_singular() {
+            return None;
+        }
+        let mut x = self.perm.apply_vec(b);
+        unsafe {
+            self.u_forward_l(&mut x);
+            self.u_backward_u(&mut x);
+        }
+        Some(x)
+    }
+
+    // Solves A^T * x = b, returning None when A is singular.
+    pub fn solve_transpose(&self, b: &[T]) -> Option<Vec<T>> {
+        assert_eq!(b.len(), self.size());
+        if self.is_singular() {
+            return None;
+        }
+        let mut z = b.to_vec();
+        unsafe {
+            self.u_forward_ut(&mut z);
+            self.u_backward_lt(&mut z);
+        }
+        Some(self.perm.inverse().apply_vec(&z))
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        let size = self.size();
+        let mut des = Matrix::zeroes(size, size);
+        let mut e = vec![T::zero(); size];
+        for col in 0..size {
+            e[col] = T::one();
+            let x = self.solve(&e)?;
+            e[col] = T::zero();
+            for (row, val) in x.into_iter().enumerate() {
+                des[(row, col)] = val;
+            }
+        }
+        Some(des)
+    }
+
+    unsafe fn u_forward_l(&self, x: &mut [T]) {
+        let size = self.size();
+        for row in 0..size {
+            let mut acc = x[row];
+            for col in 0..row {
+                acc -= unsafe { *self.lu.get(row * size + col) * *x.get_unchecked(col) };
+            }
+            x[row] = acc;
+        }
+    }
+
+    unsafe fn u_backward_u(&self, x: &mut [T]) {
+        let size = self.size();
+        for row in (0..size).rev() {
+            let mut acc = x[row];
+            for col in row+1..size {
+                acc -= unsafe { *self.lu.get(row * size + col) * *x.get_unchecked(col) };
+            }
+            x[row] = acc / unsafe { *self.lu.get(row * size + row) };
+        }
+    }
+
+    unsafe fn u_forward_ut(&self, x: &mut [T]) {
+        let size = self.size();
+        for row in 0..size {
+            let mut acc = x[row];
+            for col in 0..row {
+                acc -= unsafe { *self.lu.get(col * size + row) * *x.get_unchecked(col) };
+            }
+            x[row] = acc / unsafe { *self.lu.get(row * size + row) };
+        }
+    }
+
+    unsafe fn u_backward_lt(&self, x: &mut [T]) {
+        let size = self.size();
+        for row in (0..size).rev() {
+            let mut acc = x[row];
+            for col in row+1..size {
+                acc -= unsafe { *self.lu.get(col * size + row) * *x.get_unchecked(col) };
+            }
+            x[row] = acc;
+        }
+    }
+}
+
+impl<T: Number> Matrix<T> {
+
+    pub fn lu(&self) -> LuDecomposition<T> {
+        assert!(self.well_formed());
+        assert!(self.square());
+        unsafe { self.u_lu(self.rows) }
+    }
+
+    // Doolittle elimination with partial pivoting. A column without a nonzero pivot is
+    // skipped, leaving a zero on the diagonal of U.
+    pub unsafe fn u_lu(&self, size: usize) -> LuDecomposition<T> {
+        let mut lu = self.clone();
+        let mut perm = Permutation::identity(size);
+        unsafe {
+            for k in 0..size {
+                let mut max_index = k;
+                let mut max_val = *lu.get(k * size + k);
+                for row in k+1..size {
+                    let test_val = *lu.get(row * size + k);
+                    if magnitude_greater(test_val, max_val) {
+                        max_index = row;
+                        max_val = test_val;
+                    }
+                }
+                if max_val == T::zero() {
+                    continue;
+                }
+                if max_index != k {
+                    lu.u_swap_rows(k, max_index, size);
+                    perm.swap(k, max_index);
+                }
+                let pivot_inverse = max_val.inverse();
+                for row in k+1..size {
+                    let factor = *lu.get(row * size + k) * pivot_inverse;
+                    *lu.get_mut(row * size + k) = factor;
+                    if factor == T::zero() {
+                        continue;
+                    }
+                    for col in k+1..size {
+                        let sub_val = factor * *lu.get(k * size + col);
+                        *lu.get_mut(row * size + col) -= sub_val;
+                    }
+                }
+            }
+        }
+        LuDecomposition { lu, perm }
+    }
+}
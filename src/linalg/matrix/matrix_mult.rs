@@ -1,9 +1,9 @@
-use std::ops::Mul;
+use std::ops::{Mul, MulAssign};
 
-use crate::{core::number::Number, linalg::{matrix::matrix::Matrix, vector::{util::non_zeroed_vec, vector_dot::u_gen_vector_sdot}}};
+use crate::{core::number::Ring, linalg::{matrix::matrix::Matrix, vector::{util::zeroed_vec, vector_dot::u_gen_vector_sdot}}};
 
 #[inline(always)]
-pub fn gen_matrix_mult<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+pub fn gen_matrix_mult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
     assert!(a.well_formed());
     assert!(b.well_formed());
     assert_eq!(a.cols, b.rows);
@@ -13,12 +13,12 @@ pub fn gen_matrix_mult<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_mult<T: Number>(a: &Matrix<T>, b: &Matrix<T>, m: usize, n: usize, p:usize) -> Matrix<T> {
+pub unsafe fn u_gen_matrix_mult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, m: usize, n: usize, p:usize) -> Matrix<T> {
     unsafe {
         let mut des = Matrix {
             rows: a.rows,
             cols: b.cols,
-            data: non_zeroed_vec(m * p),
+            data: zeroed_vec(m * p),
         };
         u_gen_matrix_imult(a, b, &mut des, m, n, p);
         des
@@ -26,7 +26,7 @@ pub unsafe fn u_gen_matrix_mult<T: Number>(a: &Matrix<T>, b: &Matrix<T>, m: usiz
 }
 
 #[inline(always)]
-pub fn gen_matrix_imult<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
+pub fn gen_matrix_imult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
     assert!(a.well_formed());
     assert!(b.well_formed());
     assert!(des.well_formed());
@@ -39,7 +39,7 @@ pub fn gen_matrix_imult<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matri
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_imult<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, m: usize, n: usize, p: usize) {
+pub unsafe fn u_gen_matrix_imult<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, m: usize, n: usize, p: usize) {
     let mut a_ptr = unsafe { a.data.as_ptr().sub(n) };
     let b_ptr = b.data.as_ptr();
     for i in 0..m*p {
@@ -53,11 +53,52 @@ pub unsafe fn u_gen_matrix_imult<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &
 }
 
 
-impl<T: Number> Mul for &Matrix<T> {
+impl<T: Ring> Mul for &Matrix<T> {
     type Output = Matrix<T>;
 
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
         gen_matrix_mult(self, rhs)
     }
+}
+
+impl<T: Ring> Mul<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        gen_matrix_mult(&self, rhs)
+    }
+}
+
+impl<T: Ring> Mul<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        gen_matrix_mult(self, &rhs)
+    }
+}
+
+impl<T: Ring> Mul for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        gen_matrix_mult(&self, &rhs)
+    }
+}
+
+impl<T: Ring> MulAssign<&Matrix<T>> for Matrix<T> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = gen_matrix_mult(self, rhs);
+    }
+}
+
+impl<T: Ring> MulAssign for Matrix<T> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = gen_matrix_mult(self, &rhs);
+    }
 }
\ No newline at end of file
@@ -1,8 +1,8 @@
 use std::ops::Neg;
 
-use crate::{core::number::Number, linalg::{matrix::matrix::Matrix, vector::util::non_zeroed_vec}};
+use crate::{core::number::Ring, linalg::{matrix::matrix::Matrix, vector::util::zeroed_vec}};
 
-impl<T: Number> Matrix<T> {
+impl<T: Ring> Matrix<T> {
 
     #[inline(always)]
     pub fn negate(&mut self) {
@@ -23,7 +23,7 @@ impl<T: Number> Matrix<T> {
 }
 
 #[inline(always)]
-pub fn gen_matrix_neg<T: Number>(a: &Matrix<T>) -> Matrix<T> {
+pub fn gen_matrix_neg<T: Ring>(a: &Matrix<T>) -> Matrix<T> {
     assert!(a.well_formed());
     let size = a.size();
     unsafe {
@@ -32,12 +32,12 @@ pub fn gen_matrix_neg<T: Number>(a: &Matrix<T>) -> Matrix<T> {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_neg<T: Number>(a: &Matrix<T>, size: usize) -> Matrix<T> {
+pub unsafe fn u_gen_matrix_neg<T: Ring>(a: &Matrix<T>, size: usize) -> Matrix<T> {
     unsafe {
         let mut des = Matrix {
             rows: a.rows,
             cols: a.cols,
-            data: non_zeroed_vec(size),
+            data: zeroed_vec(size),
         };
         u_gen_matrix_ineg(a, &mut des, size);
         des
@@ -45,7 +45,7 @@ pub unsafe fn u_gen_matrix_neg<T: Number>(a: &Matrix<T>, size: usize) -> Matrix<
 }
 
 #[inline(always)]
-pub fn gen_matrix_ineg<T: Number>(a: &Matrix<T>, des: &mut Matrix<T>) {
+pub fn gen_matrix_ineg<T: Ring>(a: &Matrix<T>, des: &mut Matrix<T>) {
     assert!(a.well_formed());
     assert!(des.well_formed());
     assert_eq!(a.shape(), des.shape());
@@ -55,7 +55,7 @@ pub fn gen_matrix_ineg<T: Number>(a: &Matrix<T>, des: &mut Matrix<T>) {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_ineg<T: Number>(a: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
+pub unsafe fn u_gen_matrix_ineg<T: Ring>(a: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
     for i in 0..size {
         unsafe {
             *des.get_mut(i) = -*a.get(i);
@@ -63,11 +63,21 @@ pub unsafe fn u_gen_matrix_ineg<T: Number>(a: &Matrix<T>, des: &mut Matrix<T>, s
     }
 }
 
-impl<T: Number> Neg for &Matrix<T> {
+impl<T: Ring> Neg for &Matrix<T> {
     type Output = Matrix<T>;
 
     #[inline(always)]
     fn neg(self) -> Self::Output {
         gen_matrix_neg(self)
     }
+}
+
+impl<T: Ring> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn neg(mut self) -> Self::Output {
+        self.negate();
+        self
+    }
 }
\ No newline at end of file
@@ -0,0 +1,197 @@
+use crate::{core::{complex::Complex, number::{int, RealNumber}}, linalg::{matrix::{matrix::Matrix, matrix_lu::LuDecomposition, matrix_vector_mult::{gen_matrix_vector_mult, gen_vector_matrix_mult}}, vector::vector_norm::{gen_vector_norm_l1, gen_vector_norm_l2}}};
+
+const POWER_ITERATION_MAX_STEPS: usize = 1000;
+const CONDITION_ESTIMATE_MAX_STEPS: usize = 5;
+
+// The gap between 1 and the next representable number.
+pub(crate) fn machine_epsilon<T: RealNumber>() -> T {
+    let two = T::one() + T::one();
+    let mut eps = T::one();
+    while T::one() + eps / two > T::one() {
+        eps /= two;
+    }
+    eps
+}
+
+impl<T: RealNumber> Matrix<T> {
+
+    pub fn norm_frobenius(&self) -> T {
+        assert!(self.well_formed());
+        gen_vector_norm_l2(&self.data)
+    }
+
+    // The largest absolute column sum.
+    pub fn norm_1(&self) -> T {
+        assert!(self.well_formed());
+        self.cols_iter().fold(T::zero(), |acc, col| {
+            let sum = col.iter().fold(T::zero(), |sum, val| sum + val.abs());
+            if sum > acc { sum } else { acc }
+        })
+    }
+
+    // The largest absolute row sum.
+    pub fn norm_inf(&self) -> T {
+        assert!(self.well_formed());
+        self.rows_iter().fold(T::zero(), |acc, row| {
+            let sum = gen_vector_norm_l1(row);
+            if sum > acc { sum } else { acc }
+        })
+    }
+
+    pub fn norm_max(&self) -> T {
+        assert!(self.well_formed());
+        self.data.iter().fold(T::zero(), |acc, val| {
+            let x = val.abs();
+            if x > acc { x } else { acc }
+        })
+    }
+
+    // The largest singular value, by power iteration on A^T * A started from the row of
+    // largest norm. The estimate never decreases, so iteration stops once it settles.
+    pub fn norm_2(&self) -> T {
+        assert!(self.well_formed());
+        let start = self.rows_iter().max_by(|a, b| {
+            gen_vector_norm_l2(a).partial_cmp(&gen_vector_norm_l2(b)).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let mut v = match start {
+            Some(row) => row.to_vec(),
+            None => return T::zero(),
+        };
+        let start_norm = gen_vector_norm_l2(&v);
+        if start_norm == T::zero() {
+            return T::zero();
+        }
+        v.iter_mut().for_each(|val| *val /= start_norm);
+        let eps = machine_epsilon::<T>();
+        let mut sigma = T::zero();
+        for _ in 0..POWER_ITERATION_MAX_STEPS {
+            let w = gen_matrix_vector_mult(self, &v);
+            let next = gen_vector_norm_l2(&w);
+            if next <= sigma {
+                break;
+            }
+            let settled = next - sigma <= eps * next;
+            sigma = next;
+            if settled {
+                break;
+            }
+            v = gen_vector_matrix_mult(&w, self);
+            let v_norm = gen_vector_norm_l2(&v);
+            if v_norm == T::zero() {
+                break;
+            }
+            v.iter_mut().for_each(|val| *val /= v_norm);
+        }
+        sigma
+    }
+
+    // ||A||_1 * ||A^-1||_1 from an explicit inverse, or None when A is singular.
+    pub fn condition_number_1(&self) -> Option<T> {
+        let inverse = self.lu().inverse()?;
+        Some(self.norm_1() * inverse.norm_1())
+    }
+
+    // A lower bound on ||A||_1 * ||A^-1||_1 that is almost always within a small factor of it,
+    // costing a handful of triangular solves instead of an inverse. None when A is singular.
+    pub fn condition_number_1_estimate(&self) -> Option<T> {
+        let lu = self.lu();
+        Some(self.norm_1() * lu.inverse_norm_1_estimate()?)
+    }
+}
+
+impl<T: RealNumber> LuDecomposition<T> {
+
+    // Hager's method with Higham's refinements (LAPACK xLACON): a gradient ascent of
+    // ||A^-1 * x||_1 over the unit l1 ball, checked against an alternating test vector.
+    pub fn inverse_norm_1_estimate(&self) -> Option<T> {
+        if self.is_singular() {
+            return None;
+        }
+        estimate_norm_1(self.size(), |x| self.solve(x), |x| self.solve_transpose(x))
+    }
+
+    // ||A^-1||_inf is ||A^-T||_1, so this runs the same estimate with the solves swapped.
+    pub fn inverse_norm_inf_estimate(&self) -> Option<T> {
+        if self.is_singular() {
+            return None;
+        }
+        estimate_norm_1(self.size(), |x| self.solve_transpose(x), |x| self.solve(x))
+    }
+}
+
+// Estimates ||B||_1 for an operator B known only through products with B and B^T.
+fn estimate_norm_1<T: RealNumber, F, G>(size: usize, apply: F, apply_transpose: G) -> Option<T>
+where
+    F: Fn(&[T]) -> Option<Vec<T>>,
+    G: Fn(&[T]) -> Option<Vec<T>>,
+{
+    if size == 0 {
+        return Some(T::zero());
+    }
+    let n = int::<T>(size);
+    let mut x = vec![T::one() / n; size];
+    let mut estimate = T::zero();
+    for step in 0..CONDITION_ESTIMATE_MAX_STEPS {
+        let y = apply(&x)?;
+        let y_norm = gen_vector_norm_l1(&y);
+        if step > 0 && y_norm <= estimate {
+            break;
+        }
+        estimate = y_norm;
+        let signs: Vec<T> = y.iter().map(|val| if *val < T::zero() { -T::one() } else { T::one() }).collect();
+        let z = apply_transpose(&signs)?;
+        let mut max_index = 0;
+        for i in 1..size {
+            if z[i].abs() > z[max_index].abs() {
+                max_index = i;
+            }
+        }
+        let z_dot_x = z.iter().zip(x.iter()).fold(T::zero(), |acc, (a, b)| acc + *a * *b);
+        if step > 0 && z[max_index].abs() <= z_dot_x {
+            break;
+        }
+        x = vec![T::zero(); size];
+        x[max_index] = T::one();
+    }
+    // Catches matrices where the gradient steps stall at a poor local maximum.
+    let denom = if size > 1 { n - T::one() } else { T::one() };
+    let mut sign = T::one();
+    let mut i_val = T::zero();
+    let mut alt = Vec::with_capacity(size);
+    for _ in 0..size {
+        alt.push(sign * (T::one() + i_val / denom));
+        sign = -sign;
+        i_val += T::one();
+    }
+    let alt_norm = gen_vector_norm_l1(&apply(&alt)?);
+    let three = T::one() + T::one() + T::one();
+    let alt_estimate = (T::one() + T::one()) * alt_norm / (three * n);
+    if alt_estimate > estimate {
+        estimate = alt_estimate;
+    }
+    Some(estimate)
+}
+
+// Complex matrices measured through the moduli of their entries.
+impl<T: RealNumber> Matrix<Complex<T>> {
+
+    pub fn moduli(&self) -> Matrix<T> {
+        Matrix::new(self.rows, self.cols, self.data.iter().map(|z| z.abs()).collect())
+    }
+
+    pub fn norm_frobenius(&self) -> T {
+        self.moduli().norm_frobenius()
+    }
+
+    pub fn norm_1(&self) -> T {
+        self.moduli().norm_1()
+    }
+
+    pub fn norm_inf(&self) -> T {
+        self.moduli().norm_inf()
+    }
+
+    pub fn norm_max(&self) -> T {
+        self.moduli().norm_max()
+    }
+}
@@ -0,0 +1,315 @@
+//! Hermite and Smith normal forms of integer matrices, for i64 only. They are not generic over
+//! Ring: Ring requires Copy, which rules out arbitrary precision integers, and the fixed width
+//! integer rings would all need the widened checked arithmetic below, so i64 entries are
+//! computed in i128. solve_diophantine_system builds on the same code.
+//!
+//! Every fallible function here returns Err(IntegerOverflow) when an intermediate entry
+//! overflows i128 or an entry of the result does not fit in i64. It never wraps, never panics
+//! on overflow and never returns a partial result, so an Ok result is exact.
+
+use crate::linalg::matrix::{matrix::Matrix, matrix_transpose::gen_matrix_transpose};
+
+// An entry outgrew i128 while computing, or the result does not fit in i64. Retrying with
+// the same input fails the same way; there is no wider fallback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntegerOverflow;
+
+// U * A = H with U unimodular and H in row Hermite normal form: H is in row echelon form,
+// every pivot is positive and the entries above a pivot lie in [0, pivot).
+pub struct HermiteNormalForm {
+    pub h: Matrix<i64>,
+    pub u: Matrix<i64>,
+    pub pivots: Vec<usize>,
+}
+
+// U * A * V = D with U and V unimodular and D diagonal, its diagonal d_1 | d_2 | ... | d_r
+// positive and followed by zeros.
+pub struct SmithNormalForm {
+    pub d: Matrix<i64>,
+    pub u: Matrix<i64>,
+    pub v: Matrix<i64>,
+}
+
+impl HermiteNormalForm {
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+}
+
+impl SmithNormalForm {
+
+    // The nonzero diagonal entries d_1 | d_2 | ... | d_r.
+    pub fn invariant_factors(&self) -> Vec<i64> {
+        self.d.diagonal_iter().copied().take_while(|val| *val != 0).collect()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.invariant_factors().len()
+    }
+}
+
+// Both forms are computed in checked i128 arithmetic by unimodular row operations, pairs of
+// rows being combined by the extended Euclidean algorithm. Entries are kept small by reducing
+// everything above a pivot into [0, pivot) as soon as the pivot is placed, and by keeping the
+// null space rows of U and columns of V in Hermite form with the rest reduced against them,
+// which the transforms are otherwise free to let grow. The Smith transforms still pick up
+// gcd coefficients of the size of D, so past about 6x6 they may not fit in i64 even where D
+// does, which is reported as IntegerOverflow rather than wrapped.
+impl Matrix<i64> {
+
+    pub fn hermite_normal_form(&self) -> Result<HermiteNormalForm, IntegerOverflow> {
+        assert!(self.well_formed());
+        let (h, u, pivots) = hermite_wide(widen(self))?;
+        Ok(HermiteNormalForm { h: narrow(&h)?, u: narrow(&u)?, pivots })
+    }
+
+    pub fn smith_normal_form(&self) -> Result<SmithNormalForm, IntegerOverflow> {
+        assert!(self.well_formed());
+        let (d, u, v) = smith_wide(widen(self))?;
+        Ok(SmithNormalForm { d: narrow(&d)?, u: narrow(&u)?, v: narrow(&v)? })
+    }
+}
+
+// Intermediate results are kept in i128.
+type Wide = Matrix<i128>;
+
+fn widen(a: &Matrix<i64>) -> Matrix<i128> {
+    Matrix::new(a.rows, a.cols, a.data.iter().map(|val| *val as i128).collect())
+}
+
+fn narrow(a: &Matrix<i128>) -> Result<Matrix<i64>, IntegerOverflow> {
+    let data = a.data.iter().map(|val| i64::try_from(*val).map_err(|_| IntegerOverflow)).collect::<Result<_, _>>()?;
+    Ok(Matrix::new(a.rows, a.cols, data))
+}
+
+// Builds the form one row of A at a time, as Kannan and Bachem do, keeping what has been
+// built fully reduced after every row so that intermediate entries stay bounded by minors of A.
+// A new row is folded into each basis row whose pivot column it reaches, by the extended
+// Euclidean algorithm: with g = s * a + t * b the gcd of the two leading entries,
+// [[s, t], [-b / g, a / g]] has determinant 1 and leaves g over a zero. It joins the basis
+// where it first has a leading entry no basis row has, or ends as a null row of U.
+fn hermite_wide(a: Matrix<i128>) -> Result<(Wide, Wide, Vec<usize>), IntegerOverflow> {
+    let (rows, cols) = (a.rows, a.cols);
+    // (row of H, row of U, pivot column), ordered by pivot column.
+    let mut basis: Vec<(Vec<i128>, Vec<i128>, usize)> = Vec::new();
+    let mut null_rows = Vec::new();
+    for k in 0..rows {
+        let mut h_row: Vec<i128> = (0..cols).map(|col| a[(k, col)]).collect();
+        let mut u_row: Vec<i128> = (0..rows).map(|col| if col == k { 1 } else { 0 }).collect();
+        loop {
+            let Some(lead) = h_row.iter().position(|val| *val != 0) else {
+                null_rows.push(u_row);
+                break;
+            };
+            let index = basis.partition_point(|(_, _, col)| *col < lead);
+            if basis.get(index).is_some_and(|(_, _, col)| *col == lead) {
+                let (g, s, t) = extended_gcd(basis[index].0[lead], h_row[lead]);
+                let op = [s, t, -h_row[lead] / g, basis[index].0[lead] / g];
+                let (basis_h, basis_u, _) = &mut basis[index];
+                combine(basis_h, &mut h_row, op)?;
+                combine(basis_u, &mut u_row, op)?;
+                reduce_basis_row(&mut basis, index)?;
+                reduce_against_basis(&basis, index + 1, &mut h_row, &mut u_row)?;
+            } else {
+                if h_row[lead] < 0 {
+                    negate(&mut h_row)?;
+                    negate(&mut u_row)?;
+                }
+                basis.insert(index, (h_row, u_row, lead));
+                reduce_basis_row(&mut basis, index)?;
+                break;
+            }
+        }
+    }
+    let pivots: Vec<usize> = basis.iter().map(|(_, _, col)| *col).collect();
+    let mut h = Matrix::zeroes(rows, cols);
+    let mut u = Matrix::zeroes(rows, rows);
+    for (row, (h_row, u_row, _)) in basis.iter().enumerate() {
+        h_row.iter().enumerate().for_each(|(col, val)| h[(row, col)] = *val);
+        u_row.iter().enumerate().for_each(|(col, val)| u[(row, col)] = *val);
+    }
+    for (row, u_row) in null_rows.iter().enumerate() {
+        u_row.iter().enumerate().for_each(|(col, val)| u[(pivots.len() + row, col)] = *val);
+    }
+    reduce_against_null_rows(&mut u, pivots.len())?;
+    Ok((h, u, pivots))
+}
+
+// Restores the form after basis row index changed: reduces it against the rows below, then
+// the rows above against it and the rows below, which it may have disturbed.
+fn reduce_basis_row(basis: &mut [(Vec<i128>, Vec<i128>, usize)], index: usize) -> Result<(), IntegerOverflow> {
+    let (above, rest) = basis.split_at_mut(index);
+    let ((h_row, u_row, col), below) = rest.split_first_mut().unwrap();
+    reduce_against_basis(below, 0, h_row, u_row)?;
+    let col = *col;
+    for (above_h, above_u, _) in above.iter_mut() {
+        if above_h[col] != 0 {
+            reduce_against_basis(rest, 0, above_h, above_u)?;
+        }
+    }
+    Ok(())
+}
+
+// Brings the entries of h_row in the pivot columns of basis[start..] into [0, pivot).
+fn reduce_against_basis(basis: &[(Vec<i128>, Vec<i128>, usize)], start: usize, h_row: &mut [i128], u_row: &mut [i128]) -> Result<(), IntegerOverflow> {
+    for (basis_h, basis_u, col) in basis[start..].iter() {
+        let q = h_row[*col].div_euclid(basis_h[*col]);
+        if q != 0 {
+            add_multiple(h_row, basis_h, -q)?;
+            add_multiple(u_row, basis_u, -q)?;
+        }
+    }
+    Ok(())
+}
+
+// Rows from rank on of a transform span the null space of what it acts on, so they can be
+// replaced by their own Hermite form and added to the rows above freely.
+fn reduce_against_null_rows(u: &mut Matrix<i128>, rank: usize) -> Result<(), IntegerOverflow> {
+    if rank == u.rows {
+        return Ok(());
+    }
+    let null_rows = Matrix::from_fn(u.rows - rank, u.cols, |row, col| u[(rank + row, col)]);
+    let (null_rows, _, null_pivots) = hermite_wide(null_rows)?;
+    for ((row, col), val) in null_rows.iter_indexed() {
+        u[(rank + row, col)] = *val;
+    }
+    for (null_row, col) in null_pivots.into_iter().enumerate() {
+        let pivot = null_rows[(null_row, col)];
+        for row in 0..rank {
+            let q = u[(row, col)].div_euclid(pivot);
+            if q != 0 {
+                add_row_multiple(u, row, rank + null_row, -q)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Alternates row and column Hermite forms, the latter as the row form of the transpose,
+// until the matrix is diagonal, then makes each diagonal entry divide the next by replacing
+// pairs a, b with gcd(a, b), lcm(a, b).
+pub(crate) fn smith_wide(a: Matrix<i128>) -> Result<(Wide, Wide, Wide), IntegerOverflow> {
+    // The first form computed carries the null space in its transform, and stays far smaller
+    // when that is the larger of U and V. V^T * A^T * U^T = D^T swaps the two.
+    if a.rows < a.cols {
+        let (d, u, v) = smith_wide(gen_matrix_transpose(&a))?;
+        return Ok((gen_matrix_transpose(&d), gen_matrix_transpose(&v), gen_matrix_transpose(&u)));
+    }
+    let mut d = a;
+    let mut u = Matrix::identity(d.rows);
+    let mut v = Matrix::identity(d.cols);
+    loop {
+        let (h, step, _) = hermite_wide(d)?;
+        d = h;
+        u = checked_mult(&step, &u)?;
+        if is_diagonal(&d) {
+            break;
+        }
+        let (h, step, _) = hermite_wide(gen_matrix_transpose(&d))?;
+        d = gen_matrix_transpose(&h);
+        v = checked_mult(&v, &gen_matrix_transpose(&step))?;
+        if is_diagonal(&d) {
+            break;
+        }
+    }
+    // Either form being diagonal puts its positive pivots first along the diagonal.
+    let rank = d.diagonal_iter().take_while(|val| **val != 0).count();
+    // With g = s * a + t * b, [[s, t], [-b / g, a / g]] * diag(a, b) * [[1, -t * b / g], [1, s * a / g]]
+    // is diag(g, a * b / g), both transforms having determinant 1.
+    for i in 0..rank {
+        for j in i+1..rank {
+            let (a, b) = (d[(i, i)], d[(j, j)]);
+            if b % a == 0 {
+                continue;
+            }
+            let (g, s, t) = extended_gcd(a, b);
+            d[(i, i)] = g;
+            d[(j, j)] = (a / g).checked_mul(b).ok_or(IntegerOverflow)?;
+            combine_rows(&mut u, i, j, [s, t, -b / g, a / g])?;
+            let mut v_t = gen_matrix_transpose(&v);
+            let v_op = [1, 1, checked_dot(-t, b / g, 0, 0)?, checked_dot(s, a / g, 0, 0)?];
+            combine_rows(&mut v_t, i, j, v_op)?;
+            v = gen_matrix_transpose(&v_t);
+        }
+    }
+    reduce_against_null_rows(&mut u, rank)?;
+    let mut v_t = gen_matrix_transpose(&v);
+    reduce_against_null_rows(&mut v_t, rank)?;
+    Ok((d, u, gen_matrix_transpose(&v_t)))
+}
+
+fn is_diagonal(a: &Matrix<i128>) -> bool {
+    a.iter_indexed().all(|((row, col), val)| row == col || *val == 0)
+}
+
+// (g, s, t) with g = gcd(a, b) >= 0 and s * a + t * b = g.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+    if old_r < 0 { (-old_r, -old_s, -old_t) } else { (old_r, old_s, old_t) }
+}
+
+// x, y become op[0] * x + op[1] * y and op[2] * x + op[3] * y.
+fn combine(x: &mut [i128], y: &mut [i128], op: [i128; 4]) -> Result<(), IntegerOverflow> {
+    for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+        (*x, *y) = (checked_dot(op[0], *x, op[1], *y)?, checked_dot(op[2], *x, op[3], *y)?);
+    }
+    Ok(())
+}
+
+fn add_multiple(target: &mut [i128], source: &[i128], factor: i128) -> Result<(), IntegerOverflow> {
+    for (target, source) in target.iter_mut().zip(source.iter()) {
+        *target = checked_dot(1, *target, factor, *source)?;
+    }
+    Ok(())
+}
+
+fn negate(x: &mut [i128]) -> Result<(), IntegerOverflow> {
+    for val in x.iter_mut() {
+        *val = val.checked_neg().ok_or(IntegerOverflow)?;
+    }
+    Ok(())
+}
+
+// Rows r1, r2 of a matrix combined as by combine.
+fn combine_rows(a: &mut Matrix<i128>, r1: usize, r2: usize, op: [i128; 4]) -> Result<(), IntegerOverflow> {
+    let mut x: Vec<i128> = (0..a.cols).map(|col| a[(r1, col)]).collect();
+    let mut y: Vec<i128> = (0..a.cols).map(|col| a[(r2, col)]).collect();
+    combine(&mut x, &mut y, op)?;
+    for col in 0..a.cols {
+        (a[(r1, col)], a[(r2, col)]) = (x[col], y[col]);
+    }
+    Ok(())
+}
+
+fn add_row_multiple(a: &mut Matrix<i128>, target: usize, source: usize, factor: i128) -> Result<(), IntegerOverflow> {
+    for col in 0..a.cols {
+        a[(target, col)] = checked_dot(1, a[(target, col)], factor, a[(source, col)])?;
+    }
+    Ok(())
+}
+
+fn checked_dot(a: i128, x: i128, b: i128, y: i128) -> Result<i128, IntegerOverflow> {
+    a.checked_mul(x).and_then(|ax| b.checked_mul(y).and_then(|by| ax.checked_add(by))).ok_or(IntegerOverflow)
+}
+
+fn checked_mult(a: &Matrix<i128>, b: &Matrix<i128>) -> Result<Matrix<i128>, IntegerOverflow> {
+    let mut product = Matrix::zeroes(a.rows, b.cols);
+    for row in 0..a.rows {
+        for col in 0..b.cols {
+            let mut acc: i128 = 0;
+            for k in 0..a.cols {
+                acc = checked_dot(1, acc, a[(row, k)], b[(k, col)])?;
+            }
+            product[(row, col)] = acc;
+        }
+    }
+    Ok(product)
+}
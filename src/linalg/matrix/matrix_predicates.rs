@@ -0,0 +1,205 @@
+use crate::{core::{complex::{Complex, ComplexNumber}, number::{Number, RealNumber, Ring}}, linalg::matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult, matrix_row_reduction::magnitude, matrix_transpose::gen_matrix_transpose}};
+
+// Every predicate treats entries within tol of each other as equal, so a zero tol
+// gives exact checks for Rational and ModP and a small positive tol absorbs round-off
+// for floats.
+impl<T: Number> Matrix<T> {
+
+    pub fn is_symmetric(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        (0..self.rows).all(|row| (row+1..self.cols).all(|col| within(self[(row, col)], self[(col, row)], tol)))
+    }
+
+    pub fn is_skew_symmetric(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        (0..self.rows).all(|row| (row..self.cols).all(|col| within(self[(row, col)], -self[(col, row)], tol)))
+    }
+
+    // Everything below the main diagonal is zero; the matrix need not be square.
+    pub fn is_upper_triangular(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        self.iter_indexed().all(|((row, col), val)| row <= col || within(*val, T::zero(), tol))
+    }
+
+    pub fn is_lower_triangular(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        self.iter_indexed().all(|((row, col), val)| row >= col || within(*val, T::zero(), tol))
+    }
+
+    pub fn is_diagonal(&self, tol: T) -> bool {
+        self.is_upper_triangular(tol) && self.is_lower_triangular(tol)
+    }
+
+    // A^T * A = I.
+    pub fn is_orthogonal(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        let product = gen_matrix_mult(&gen_matrix_transpose(self), self);
+        is_identity_within(&product, tol)
+    }
+
+    // |a_ii| >= sum of |a_ij| over j != i for every row.
+    pub fn is_diagonally_dominant(&self) -> bool {
+        self.diagonal_dominance(false)
+    }
+
+    // |a_ii| > sum of |a_ij| over j != i for every row.
+    pub fn is_strictly_diagonally_dominant(&self) -> bool {
+        self.diagonal_dominance(true)
+    }
+
+    fn diagonal_dominance(&self, strict: bool) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        self.rows_iter().enumerate().all(|(row, vals)| {
+            let off_diagonal = vals.iter().enumerate()
+                .filter(|(col, _)| *col != row)
+                .fold(T::zero(), |acc, (_, val)| acc + magnitude(*val));
+            let diagonal = magnitude(vals[row]);
+            if strict { diagonal > off_diagonal } else { diagonal >= off_diagonal }
+        })
+    }
+
+    // A * A = A.
+    pub fn is_idempotent(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        let product = gen_matrix_mult(self, self);
+        product.data.iter().zip(self.data.iter()).all(|(a, b)| within(*a, *b, tol))
+    }
+
+    // A^k = 0 for some k, which holds exactly when A^n = 0. Squaring up to a power of
+    // at least n checks this in log2(n) products.
+    pub fn is_nilpotent(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        let mut power = self.clone();
+        let mut k = 1;
+        while k < self.rows {
+            power = gen_matrix_mult(&power, &power);
+            k *= 2;
+        }
+        power.data.iter().all(|val| within(*val, T::zero(), tol))
+    }
+
+    // Zero rows come last, each other row leads with a 1 strictly right of the one above,
+    // and every leading 1 is the only nonzero entry in its column.
+    pub fn is_in_rref(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        let mut next_col = 0;
+        let mut seen_zero_row = false;
+        for row in 0..self.rows {
+            let lead = (0..self.cols).find(|col| !within(self[(row, *col)], T::zero(), tol));
+            let lead = match lead {
+                Some(col) => col,
+                None => {
+                    seen_zero_row = true;
+                    continue;
+                }
+            };
+            if seen_zero_row || lead < next_col || !within(self[(row, lead)], T::one(), tol) {
+                return false;
+            }
+            if (0..self.rows).any(|other| other != row && !within(self[(other, lead)], T::zero(), tol)) {
+                return false;
+            }
+            next_col = lead + 1;
+        }
+        true
+    }
+}
+
+// Positive definiteness needs a Hermitian matrix, which for real types is a symmetric one.
+impl<T: ComplexNumber> Matrix<T> {
+
+    // Attempts the square-root-free Cholesky factorization A = L * D * L^H, which succeeds
+    // with every entry of D above tol exactly when a Hermitian A is positive definite.
+    // Avoiding square roots keeps the check exact over Rational.
+    pub fn is_positive_definite(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        let hermitian = (0..self.rows).all(|row| {
+            (row..self.cols).all(|col| within(self[(row, col)], self[(col, row)].conjugate(), tol))
+        });
+        if !hermitian {
+            return false;
+        }
+        let size = self.rows;
+        let mut l = Matrix::<T>::zeroes(size, size);
+        let mut d = vec![T::zero(); size];
+        for j in 0..size {
+            let mut dj = self[(j, j)];
+            for k in 0..j {
+                dj -= l[(j, k)] * l[(j, k)].conjugate() * d[k];
+            }
+            if dj <= tol {
+                return false;
+            }
+            d[j] = dj;
+            for i in j+1..size {
+                let mut val = self[(i, j)];
+                for k in 0..j {
+                    val -= l[(i, k)] * l[(j, k)].conjugate() * d[k];
+                }
+                l[(i, j)] = val / dj;
+            }
+        }
+        true
+    }
+}
+
+// Complex matrices compare entries by the modulus of their difference.
+impl<T: RealNumber> Matrix<Complex<T>> {
+
+    // A = A^H.
+    pub fn is_hermitian(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        (0..self.rows).all(|row| {
+            (row..self.cols).all(|col| (self[(row, col)] - self[(col, row)].conjugate()).abs() <= tol)
+        })
+    }
+
+    // A^H * A = I.
+    pub fn is_unitary(&self, tol: T) -> bool {
+        assert!(self.well_formed());
+        if !self.square() {
+            return false;
+        }
+        let adjoint = Matrix::from_fn(self.cols, self.rows, |row, col| self[(col, row)].conjugate());
+        let product = gen_matrix_mult(&adjoint, self);
+        product.iter_indexed().all(|((row, col), val)| {
+            let target = if row == col { Complex::one() } else { Complex::zero() };
+            (*val - target).abs() <= tol
+        })
+    }
+}
+
+fn within<T: Number>(a: T, b: T, tol: T) -> bool {
+    magnitude(a - b) <= tol
+}
+
+fn is_identity_within<T: Number>(a: &Matrix<T>, tol: T) -> bool {
+    a.iter_indexed().all(|((row, col), val)| {
+        let target = if row == col { T::one() } else { T::zero() };
+        within(*val, target, tol)
+    })
+}
@@ -0,0 +1,107 @@
+use crate::{core::number::RealNumber, linalg::matrix::matrix::Matrix};
+
+// A * P = Q * R where column j of A * P is column perm[j] of A.
+pub struct QrDecomposition<T: RealNumber> {
+    pub q: Matrix<T>,
+    pub r: Matrix<T>,
+    pub perm: Vec<usize>,
+}
+
+impl<T: RealNumber> QrDecomposition<T> {
+    // Number of diagonal entries of R larger than tol in magnitude.
+    pub fn rank(&self, tol: T) -> usize {
+        let steps = self.r.rows.min(self.r.cols);
+        (0..steps).take_while(|i| self.r[(*i, *i)].abs() > tol).count()
+    }
+}
+
+impl<T: RealNumber> Matrix<T> {
+
+    pub fn qr(&self) -> QrDecomposition<T> {
+        assert!(self.well_formed());
+        unsafe { self.u_qr(self.rows, self.cols, false) }
+    }
+
+    pub fn qr_pivoted(&self) -> QrDecomposition<T> {
+        assert!(self.well_formed());
+        unsafe { self.u_qr(self.rows, self.cols, true) }
+    }
+
+    // Householder QR, optionally moving the remaining column of largest norm into place at each step.
+    pub unsafe fn u_qr(&self, rows: usize, cols: usize, pivoting: bool) -> QrDecomposition<T> {
+        let mut r = self.clone();
+        let mut q = Matrix::identity(rows);
+        let mut perm: Vec<usize> = (0..cols).collect();
+        let mut v = vec![T::zero(); rows];
+        let two = T::one() + T::one();
+        unsafe {
+            for k in 0..rows.min(cols) {
+                if pivoting {
+                    let mut max_col = k;
+                    let mut max_norm = T::zero();
+                    for col in k..cols {
+                        let mut norm = T::zero();
+                        for row in k..rows {
+                            let val = *r.get(row * cols + col);
+                            norm += val * val;
+                        }
+                        if norm > max_norm {
+                            max_col = col;
+                            max_norm = norm;
+                        }
+                    }
+                    if max_col != k {
+                        for row in 0..rows {
+                            std::ptr::swap(r.get_mut(row * cols + k), r.get_mut(row * cols + max_col));
+                        }
+                        perm.swap(k, max_col);
+                    }
+                }
+                let mut norm = T::zero();
+                for row in k..rows {
+                    let val = *r.get(row * cols + k);
+                    norm += val * val;
+                }
+                norm = norm.sqrt();
+                if norm == T::zero() {
+                    continue;
+                }
+                let head = *r.get(k * cols + k);
+                let alpha = if head > T::zero() { -norm } else { norm };
+                let mut v_norm = T::zero();
+                for row in k..rows {
+                    let val = *r.get(row * cols + k);
+                    *v.get_unchecked_mut(row) = if row == k { val - alpha } else { val };
+                    v_norm += *v.get_unchecked(row) * *v.get_unchecked(row);
+                }
+                if v_norm == T::zero() {
+                    continue;
+                }
+                for col in k..cols {
+                    let mut dot = T::zero();
+                    for row in k..rows {
+                        dot += *v.get_unchecked(row) * *r.get(row * cols + col);
+                    }
+                    let scale = two * dot / v_norm;
+                    for row in k..rows {
+                        *r.get_mut(row * cols + col) -= scale * *v.get_unchecked(row);
+                    }
+                }
+                for row in k+1..rows {
+                    *r.get_mut(row * cols + k) = T::zero();
+                }
+                for row in 0..rows {
+                    let mut dot = T::zero();
+                    for col in k..rows {
+                        dot += *q.get(row * rows + col) * *v.get_unchecked(col);
+                    }
+                    let scale = two * dot / v_norm;
+                    for col in k..rows {
+                        *q.get_mut(row * rows + col) -= scale * *v.get_unchecked(col);
+                    }
+                }
+            }
+        }
+        QrDecomposition { q, r, perm }
+    }
+}
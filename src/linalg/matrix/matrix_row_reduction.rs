@@ -2,27 +2,39 @@ use crate::{core::number::Number, linalg::matrix::matrix::Matrix};
 
 impl<T: Number> Matrix<T> {
 
-    pub fn row_echelon_form(&mut self) {
+    // Returns the pivot columns chosen, one per nonzero row.
+    pub fn row_echelon_form(&mut self) -> Vec<usize> {
         assert!(self.well_formed());
-        unsafe { self.u_row_echelon_form(self.rows, self.cols) };
+        unsafe { self.u_row_echelon_form(self.rows, self.cols) }
     }
 
-    pub unsafe fn u_row_echelon_form(&mut self, rows: usize, cols: usize) {
+    pub unsafe fn u_row_echelon_form(&mut self, rows: usize, cols: usize) -> Vec<usize> {
+        unsafe { self.u_row_echelon_form_tol(rows, cols, T::zero()) }
+    }
+
+    // Treats any pivot candidate within tol of zero as zero, clearing the rest of its column.
+    pub fn row_echelon_form_tol(&mut self, tol: T) -> Vec<usize> {
+        assert!(self.well_formed());
+        unsafe { self.u_row_echelon_form_tol(self.rows, self.cols, tol) }
+    }
+
+    pub unsafe fn u_row_echelon_form_tol(&mut self, rows: usize, cols: usize, tol: T) -> Vec<usize> {
         let mut start_col: usize = 0;
         let mut start_row: usize = 0;
         let mut row_offset: usize = 0;
+        let mut pivots = Vec::with_capacity(rows.min(cols));
         unsafe {
-            while start_row < rows - 1 && start_col < cols {
+            while start_row < rows && start_col < cols {
                 let mut max_index = start_row;
-                let mut max_val = *self.get(row_offset);
+                let mut max_val = *self.get(row_offset + start_col);
                 for row in start_row+1..rows {
                     let test_val = *self.get(start_col + row * cols);
-                    if test_val > max_val {
+                    if magnitude_greater(test_val, max_val) {
                         max_index = row;
                         max_val = test_val;
                     }
                 }
-                if max_val != T::zero() {
+                if magnitude(max_val) > tol {
                     self.u_swap_rows(max_index, start_row, cols);
                     for row in start_row+1..rows {
                         let inner_row_offset = row * cols;
@@ -33,35 +45,60 @@ impl<T: Number> Matrix<T> {
                             *self.get_mut(inner_row_offset + col) -= sub_val;
                         }
                     }
+                    pivots.push(start_col);
                     start_row += 1;
                     row_offset += cols;
+                } else {
+                    for row in start_row..rows {
+                        *self.get_mut(row * cols + start_col) = T::zero();
+                    }
                 }
                 start_col += 1;
             }
         }
+        pivots
     }
 
-    pub fn reduced_row_echelon_form(&mut self) {
+    // Returns the pivot columns chosen, one per nonzero row.
+    pub fn reduced_row_echelon_form(&mut self) -> Vec<usize> {
         assert!(self.well_formed());
-        unsafe { self.u_reduced_row_echelon_form(self.rows, self.cols) };
+        unsafe { self.u_reduced_row_echelon_form(self.rows, self.cols) }
+    }
+
+    pub unsafe fn u_reduced_row_echelon_form(&mut self, rows: usize, cols: usize) -> Vec<usize> {
+        unsafe { self.u_reduced_row_echelon_form_tol(rows, cols, T::zero()) }
     }
 
-    pub unsafe fn u_reduced_row_echelon_form(&mut self, rows: usize, cols: usize) {
+    // Treats any pivot candidate within tol of zero as zero, clearing the rest of its column.
+    pub fn reduced_row_echelon_form_tol(&mut self, tol: T) -> Vec<usize> {
+        assert!(self.well_formed());
+        unsafe { self.u_reduced_row_echelon_form_tol(self.rows, self.cols, tol) }
+    }
+
+    pub unsafe fn u_reduced_row_echelon_form_tol(&mut self, rows: usize, cols: usize, tol: T) -> Vec<usize> {
+        unsafe { self.u_reduced_row_echelon_form_leading(rows, cols, cols, tol) }
+    }
+
+    // Pivots only within the first pivot_cols columns while applying every row operation to
+    // the full rows, so trailing columns such as right hand sides are carried along untouched
+    // by pivoting of their own.
+    pub unsafe fn u_reduced_row_echelon_form_leading(&mut self, rows: usize, cols: usize, pivot_cols: usize, tol: T) -> Vec<usize> {
         let mut start_col: usize = 0;
         let mut start_row: usize = 0;
         let mut row_offset: usize = 0;
+        let mut pivots = Vec::with_capacity(rows.min(pivot_cols));
         unsafe {
-            while start_row < rows && start_col < cols {
+            while start_row < rows && start_col < pivot_cols {
                 let mut max_index = start_row;
                 let mut max_val = *self.get(row_offset + start_col);
                 for row in start_row+1..rows {
                     let test_val = *self.get(start_col + row * cols);
-                    if test_val > max_val {
+                    if magnitude_greater(test_val, max_val) {
                         max_index = row;
                         max_val = test_val;
                     }
                 }
-                if max_val != T::zero() {
+                if magnitude(max_val) > tol {
                     self.u_swap_rows(max_index, start_row, cols);
                     for row in 0..rows {
                         if row != start_row {
@@ -79,11 +116,28 @@ impl<T: Number> Matrix<T> {
                     for col in start_col+1..cols {
                         *self.get_mut(row_offset + col) *= mult_value;
                     }
+                    pivots.push(start_col);
                     start_row += 1;
                     row_offset += cols;
+                } else {
+                    for row in start_row..rows {
+                        *self.get_mut(row * cols + start_col) = T::zero();
+                    }
                 }
                 start_col += 1;
             }
         }
+        pivots
     }
+}
+
+#[inline(always)]
+pub(crate) fn magnitude<T: Number>(a: T) -> T {
+    a.magnitude()
+}
+
+// Picks the entry of largest magnitude, and never prefers zero.
+#[inline(always)]
+pub(crate) fn magnitude_greater<T: Number>(a: T, b: T) -> bool {
+    magnitude(a) > magnitude(b)
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use std::ops::{Div, DivAssign};
+
+use crate::{core::number::Number, linalg::{matrix::matrix::Matrix, vector::util::zeroed_vec}};
+
+impl<T: Number> Matrix<T> {
+    #[inline(always)]
+    pub fn divide(&mut self, a: T) {
+        assert!(self.well_formed());
+        unsafe {
+            self.u_divide(a, self.size());
+        }
+    }
+
+    #[inline(always)]
+    pub unsafe fn u_divide(&mut self, a: T, size: usize) {
+        for i in 0..size {
+            unsafe {
+                *self.get_mut(i) = *self.get(i) / a;
+            }
+        }
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_scaler_div<T: Number>(m: &Matrix<T>, a: T) -> Matrix<T> {
+    assert!(m.well_formed());
+    let size = m.size();
+    unsafe {
+        u_gen_matrix_scaler_div(m, a, size)
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_scaler_div<T: Number>(m: &Matrix<T>, a: T, size: usize) -> Matrix<T> {
+    unsafe {
+        let mut des = Matrix {
+            rows: m.rows,
+            cols: m.cols,
+            data: zeroed_vec(size),
+        };
+        u_gen_matrix_scaler_idiv(m, a, &mut des, size);
+        des
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_scaler_idiv<T: Number>(m: &Matrix<T>, a: T, des: &mut Matrix<T>) {
+    assert!(m.well_formed());
+    assert!(des.well_formed());
+    assert_eq!(m.shape(), des.shape());
+    unsafe {
+        u_gen_matrix_scaler_idiv(m, a, des, m.size());
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_scaler_idiv<T: Number>(m: &Matrix<T>, a: T, des: &mut Matrix<T>, size: usize) {
+    for i in 0..size {
+        unsafe {
+            *des.get_mut(i) = *m.get(i) / a;
+        }
+    }
+}
+
+impl<T: Number> Div<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn div(self, rhs: T) -> Self::Output {
+        gen_matrix_scaler_div(self, rhs)
+    }
+}
+
+impl<T: Number> Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn div(mut self, rhs: T) -> Self::Output {
+        self.divide(rhs);
+        self
+    }
+}
+
+impl<T: Number> DivAssign<T> for Matrix<T> {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: T) {
+        self.divide(rhs);
+    }
+}
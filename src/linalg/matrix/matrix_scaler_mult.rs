@@ -0,0 +1,154 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{core::{complex::Complex, modp::ModP, number::{RealNumber, Ring}, rational::Rational}, linalg::{matrix::matrix::Matrix, vector::util::zeroed_vec}};
+
+impl<T: Ring> Matrix<T> {
+    #[inline(always)]
+    pub fn scale(&mut self, a: T) {
+        assert!(self.well_formed());
+        unsafe {
+            self.u_scale(a, self.size());
+        }
+    }
+
+    #[inline(always)]
+    pub unsafe fn u_scale(&mut self, a: T, size: usize) {
+        for i in 0..size {
+            unsafe {
+                *self.get_mut(i) = a * *self.get(i);
+            }
+        }
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_scaler_mult<T: Ring>(a: T, m: &Matrix<T>) -> Matrix<T> {
+    assert!(m.well_formed());
+    let size = m.size();
+    unsafe {
+        u_gen_matrix_scaler_mult(a, m, size)
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_scaler_mult<T: Ring>(a: T, m: &Matrix<T>, size: usize) -> Matrix<T> {
+    unsafe {
+        let mut des = Matrix {
+            rows: m.rows,
+            cols: m.cols,
+            data: zeroed_vec(size),
+        };
+        u_gen_matrix_scaler_imult(a, m, &mut des, size);
+        des
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_scaler_imult<T: Ring>(a: T, m: &Matrix<T>, des: &mut Matrix<T>) {
+    assert!(m.well_formed());
+    assert!(des.well_formed());
+    assert_eq!(m.shape(), des.shape());
+    unsafe {
+        u_gen_matrix_scaler_imult(a, m, des, m.size());
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_scaler_imult<T: Ring>(a: T, m: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
+    for i in 0..size {
+        unsafe {
+            *des.get_mut(i) = a * *m.get(i);
+        }
+    }
+}
+
+impl<T: Ring> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: T) -> Self::Output {
+        gen_matrix_scaler_mult(rhs, self)
+    }
+}
+
+impl<T: Ring> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.scale(rhs);
+        self
+    }
+}
+
+impl<T: Ring> MulAssign<T> for Matrix<T> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: T) {
+        self.scale(rhs);
+    }
+}
+
+macro_rules! impl_scaler_left_mult {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                #[inline(always)]
+                fn mul(self, rhs: &Matrix<$t>) -> Self::Output {
+                    gen_matrix_scaler_mult(self, rhs)
+                }
+            }
+
+            impl Mul<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                #[inline(always)]
+                fn mul(self, mut rhs: Matrix<$t>) -> Self::Output {
+                    rhs.scale(self);
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_scaler_left_mult!(f32, f64, i8, i16, i32, i64, i128, isize, Rational);
+
+impl<T: RealNumber> Mul<&Matrix<Complex<T>>> for Complex<T> {
+    type Output = Matrix<Complex<T>>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Matrix<Complex<T>>) -> Self::Output {
+        gen_matrix_scaler_mult(self, rhs)
+    }
+}
+
+impl<T: RealNumber> Mul<Matrix<Complex<T>>> for Complex<T> {
+    type Output = Matrix<Complex<T>>;
+
+    #[inline(always)]
+    fn mul(self, mut rhs: Matrix<Complex<T>>) -> Self::Output {
+        rhs.scale(self);
+        rhs
+    }
+}
+
+impl<const P: u64> Mul<&Matrix<ModP<P>>> for ModP<P> {
+    type Output = Matrix<ModP<P>>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Matrix<ModP<P>>) -> Self::Output {
+        gen_matrix_scaler_mult(self, rhs)
+    }
+}
+
+impl<const P: u64> Mul<Matrix<ModP<P>>> for ModP<P> {
+    type Output = Matrix<ModP<P>>;
+
+    #[inline(always)]
+    fn mul(self, mut rhs: Matrix<ModP<P>>) -> Self::Output {
+        rhs.scale(self);
+        rhs
+    }
+}
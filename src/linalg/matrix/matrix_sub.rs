@@ -1,8 +1,8 @@
-use std::ops::Sub;
+use std::ops::{Sub, SubAssign};
 
-use crate::{core::number::Number, linalg::{matrix::matrix::Matrix, vector::util::non_zeroed_vec}};
+use crate::{core::number::Ring, linalg::{matrix::matrix::Matrix, vector::util::zeroed_vec}};
 
-impl<T: Number> Matrix<T> {
+impl<T: Ring> Matrix<T> {
     #[inline(always)]
     pub fn sub(&mut self, other: &Matrix<T>) {
         assert!(self.well_formed());
@@ -24,7 +24,7 @@ impl<T: Number> Matrix<T> {
 }
 
 #[inline(always)]
-pub fn gen_matrix_sub<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+pub fn gen_matrix_sub<T: Ring>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
     assert!(a.well_formed());
     assert!(b.well_formed());
     assert_eq!(a.shape(), b.shape());
@@ -35,12 +35,12 @@ pub fn gen_matrix_sub<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_sub<T: Number>(a: &Matrix<T>, b: &Matrix<T>, size: usize) -> Matrix<T> {
+pub unsafe fn u_gen_matrix_sub<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, size: usize) -> Matrix<T> {
     unsafe {
         let mut des = Matrix {
             rows: a.rows,
             cols: a.cols,
-            data: non_zeroed_vec(size),
+            data: zeroed_vec(size),
         };
         u_gen_matrix_isub(a, b, &mut des, size);
         des
@@ -48,7 +48,7 @@ pub unsafe fn u_gen_matrix_sub<T: Number>(a: &Matrix<T>, b: &Matrix<T>, size: us
 }
 
 #[inline(always)]
-pub fn gen_matrix_isub<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
+pub fn gen_matrix_isub<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>) {
     assert!(a.well_formed());
     assert!(b.well_formed());
     assert!(des.well_formed());
@@ -60,7 +60,7 @@ pub fn gen_matrix_isub<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_isub<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
+pub unsafe fn u_gen_matrix_isub<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
     for i in 0..size {
         unsafe {
             *des.get_mut(i) = *a.get(i) - *b.get(i)
@@ -68,11 +68,53 @@ pub unsafe fn u_gen_matrix_isub<T: Number>(a: &Matrix<T>, b: &Matrix<T>, des: &m
     }
 }
 
-impl<T: Number> Sub for &Matrix<T> {
+impl<T: Ring> Sub for &Matrix<T> {
     type Output = Matrix<T>;
     
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
         gen_matrix_sub(self, rhs)
     }
+}
+
+impl<T: Ring> Sub<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn sub(mut self, rhs: &Matrix<T>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Ring> Sub<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn sub(self, rhs: Matrix<T>) -> Self::Output {
+        gen_matrix_sub(self, &rhs)
+    }
+}
+
+impl<T: Ring> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl<T: Ring> SubAssign<&Matrix<T>> for Matrix<T> {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        Matrix::sub(self, rhs);
+    }
+}
+
+impl<T: Ring> SubAssign for Matrix<T> {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        Matrix::sub(self, &rhs);
+    }
 }
\ No newline at end of file
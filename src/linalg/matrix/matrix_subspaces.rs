@@ -0,0 +1,111 @@
+use crate::{core::number::{Number, RealNumber}, linalg::matrix::{matrix::Matrix, matrix_transpose::gen_matrix_transpose}};
+
+impl<T: Number> Matrix<T> {
+
+    pub fn null_space(&self) -> Vec<Vec<T>> {
+        assert!(self.well_formed());
+        let mut reduced = self.clone();
+        unsafe {
+            let pivots = reduced.u_reduced_row_echelon_form(self.rows, self.cols);
+            reduced.u_null_space_from_reduced(&pivots, self.cols)
+        }
+    }
+
+    pub fn column_space(&self) -> Vec<Vec<T>> {
+        assert!(self.well_formed());
+        let mut reduced = self.clone();
+        unsafe {
+            let pivots = reduced.u_reduced_row_echelon_form(self.rows, self.cols);
+            pivots.iter().map(|col| (0..self.rows).map(|row| *self.get(row * self.cols + col)).collect()).collect()
+        }
+    }
+
+    pub fn row_space(&self) -> Vec<Vec<T>> {
+        assert!(self.well_formed());
+        let mut reduced = self.clone();
+        unsafe {
+            let rank = reduced.u_reduced_row_echelon_form(self.rows, self.cols).len();
+            reduced.rows_iter().take(rank).map(|row| row.to_vec()).collect()
+        }
+    }
+
+    pub fn left_null_space(&self) -> Vec<Vec<T>> {
+        gen_matrix_transpose(self).null_space()
+    }
+
+    // Returns (rank, nullity).
+    pub fn rank_nullity(&self) -> (usize, usize) {
+        (self.row_space().len(), self.null_space().len())
+    }
+
+    // Checks that every null space vector is annihilated by the matrix and that rank + nullity = cols.
+    pub fn verify_rank_nullity(&self) -> bool {
+        let (rank, nullity) = self.rank_nullity();
+        if rank + nullity != self.cols {
+            return false;
+        }
+        self.null_space().iter().all(|v| {
+            self.rows_iter().all(|row| {
+                row.iter().zip(v.iter()).fold(T::zero(), |acc, (a, b)| acc + *a * *b) == T::zero()
+            })
+        })
+    }
+
+    pub unsafe fn u_null_space_from_reduced(&self, pivots: &[usize], cols: usize) -> Vec<Vec<T>> {
+        let mut is_pivot = vec![false; cols];
+        for col in pivots.iter() {
+            is_pivot[*col] = true;
+        }
+        let mut basis = Vec::with_capacity(cols - pivots.len());
+        for free_col in (0..cols).filter(|col| !is_pivot[*col]) {
+            let mut v = vec![T::zero(); cols];
+            v[free_col] = T::one();
+            for (row, pivot_col) in pivots.iter().enumerate() {
+                v[*pivot_col] = -unsafe { *self.get(row * cols + free_col) };
+            }
+            basis.push(v);
+        }
+        basis
+    }
+}
+
+// Orthonormal bases from column pivoted QR, treating diagonal entries of R below tol as zero.
+impl<T: RealNumber> Matrix<T> {
+
+    pub fn null_space_tol(&self, tol: T) -> Vec<Vec<T>> {
+        gen_matrix_transpose(self).qr_complement_columns(tol)
+    }
+
+    pub fn column_space_tol(&self, tol: T) -> Vec<Vec<T>> {
+        self.qr_range_columns(tol)
+    }
+
+    pub fn row_space_tol(&self, tol: T) -> Vec<Vec<T>> {
+        gen_matrix_transpose(self).qr_range_columns(tol)
+    }
+
+    pub fn left_null_space_tol(&self, tol: T) -> Vec<Vec<T>> {
+        self.qr_complement_columns(tol)
+    }
+
+    pub fn rank_tol(&self, tol: T) -> usize {
+        self.qr_pivoted().rank(tol)
+    }
+
+    pub fn rank_nullity_tol(&self, tol: T) -> (usize, usize) {
+        let rank = self.rank_tol(tol);
+        (rank, self.cols - rank)
+    }
+
+    fn qr_range_columns(&self, tol: T) -> Vec<Vec<T>> {
+        let qr = self.qr_pivoted();
+        let rank = qr.rank(tol);
+        (0..rank).map(|col| (0..self.rows).map(|row| qr.q[(row, col)]).collect()).collect()
+    }
+
+    fn qr_complement_columns(&self, tol: T) -> Vec<Vec<T>> {
+        let qr = self.qr_pivoted();
+        let rank = qr.rank(tol);
+        (rank..self.rows).map(|col| (0..self.rows).map(|row| qr.q[(row, col)]).collect()).collect()
+    }
+}
@@ -1,6 +1,6 @@
-use crate::{core::number::Number, linalg::{matrix::matrix::Matrix, vector::util::non_zeroed_vec}};
+use crate::{core::number::Ring, linalg::{matrix::matrix::Matrix, vector::util::zeroed_vec}};
 
-impl<T: Number> Matrix<T> {
+impl<T: Ring> Matrix<T> {
 
     #[inline(always)]
     pub fn transpose(&mut self) {
@@ -17,7 +17,7 @@ impl<T: Number> Matrix<T> {
 }
 
 #[inline(always)]
-pub fn gen_matrix_transpose<T: Number>(a: &Matrix<T>) -> Matrix<T> {
+pub fn gen_matrix_transpose<T: Ring>(a: &Matrix<T>) -> Matrix<T> {
     assert!(a.well_formed());
     let size = a.size();
     unsafe {
@@ -26,12 +26,12 @@ pub fn gen_matrix_transpose<T: Number>(a: &Matrix<T>) -> Matrix<T> {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_transpose<T: Number>(a: &Matrix<T>, size: usize) -> Matrix<T> {
+pub unsafe fn u_gen_matrix_transpose<T: Ring>(a: &Matrix<T>, size: usize) -> Matrix<T> {
     unsafe {
         let mut des = Matrix {
             rows: a.cols,
             cols: a.rows,
-            data: non_zeroed_vec(size),
+            data: zeroed_vec(size),
         };
         u_gen_matrix_itranspose(a, &mut des, size);
         des
@@ -39,7 +39,7 @@ pub unsafe fn u_gen_matrix_transpose<T: Number>(a: &Matrix<T>, size: usize) -> M
 }
 
 #[inline(always)]
-pub fn gen_matrix_itranspose<T: Number>(a: &Matrix<T>, des: &mut Matrix<T>) {
+pub fn gen_matrix_itranspose<T: Ring>(a: &Matrix<T>, des: &mut Matrix<T>) {
     assert!(a.well_formed());
     assert!(des.well_formed());
     assert_eq!(a.rows, des.cols);
@@ -50,10 +50,10 @@ pub fn gen_matrix_itranspose<T: Number>(a: &Matrix<T>, des: &mut Matrix<T>) {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_matrix_itranspose<T: Number>(a: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
+pub unsafe fn u_gen_matrix_itranspose<T: Ring>(a: &Matrix<T>, des: &mut Matrix<T>, size: usize) {
     for i in 0..size {
         unsafe {
-            let loc = a.index_to_location(i);
+            let loc = des.index_to_location(i);
             *des.get_mut(i) = *a.get_loc(loc.1, loc.0);
         }
     }
@@ -0,0 +1,139 @@
+use std::ops::Mul;
+
+use crate::{core::number::Ring, linalg::{matrix::matrix::Matrix, vector::{util::zeroed_vec, vector::Vector, vector_dot::u_gen_vector_dot}}};
+
+#[inline(always)]
+pub fn gen_matrix_vector_mult<T: Ring>(a: &Matrix<T>, v: &[T]) -> Vec<T> {
+    assert!(a.well_formed());
+    assert_eq!(a.cols, v.len());
+    unsafe {
+        u_gen_matrix_vector_mult(a, v.as_ptr(), a.rows, a.cols)
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_vector_mult<T: Ring>(a: &Matrix<T>, v: *const T, m: usize, n: usize) -> Vec<T> {
+    unsafe {
+        let mut des = zeroed_vec(m);
+        u_gen_matrix_vector_imult(a, v, des.as_mut_ptr(), m, n);
+        des
+    }
+}
+
+#[inline(always)]
+pub fn gen_matrix_vector_imult<T: Ring>(a: &Matrix<T>, v: &[T], des: &mut [T]) {
+    assert!(a.well_formed());
+    assert_eq!(a.cols, v.len());
+    assert_eq!(a.rows, des.len());
+    unsafe {
+        u_gen_matrix_vector_imult(a, v.as_ptr(), des.as_mut_ptr(), a.rows, a.cols);
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_matrix_vector_imult<T: Ring>(a: &Matrix<T>, v: *const T, des: *mut T, m: usize, n: usize) {
+    let a_ptr = a.data.as_ptr();
+    for i in 0..m {
+        unsafe {
+            *des.add(i) = u_gen_vector_dot(a_ptr.add(i * n), v, n);
+        }
+    }
+}
+
+// The row vector v * a.
+#[inline(always)]
+pub fn gen_vector_matrix_mult<T: Ring>(v: &[T], a: &Matrix<T>) -> Vec<T> {
+    assert!(a.well_formed());
+    assert_eq!(a.rows, v.len());
+    unsafe {
+        u_gen_vector_matrix_mult(v.as_ptr(), a, a.rows, a.cols)
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_matrix_mult<T: Ring>(v: *const T, a: &Matrix<T>, m: usize, n: usize) -> Vec<T> {
+    let mut des = vec![T::zero(); n];
+    for i in 0..m {
+        unsafe {
+            let factor = *v.add(i);
+            for j in 0..n {
+                *des.get_unchecked_mut(j) += factor * *a.get(i * n + j);
+            }
+        }
+    }
+    des
+}
+
+impl<T: Ring> Mul<&[T]> for &Matrix<T> {
+    type Output = Vec<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &[T]) -> Self::Output {
+        gen_matrix_vector_mult(self, rhs)
+    }
+}
+
+impl<T: Ring> Mul<&[T]> for Matrix<T> {
+    type Output = Vec<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &[T]) -> Self::Output {
+        gen_matrix_vector_mult(&self, rhs)
+    }
+}
+
+impl<T: Ring> Mul<&Vec<T>> for &Matrix<T> {
+    type Output = Vec<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Vec<T>) -> Self::Output {
+        gen_matrix_vector_mult(self, rhs)
+    }
+}
+
+impl<T: Ring> Mul<&Vec<T>> for Matrix<T> {
+    type Output = Vec<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Vec<T>) -> Self::Output {
+        gen_matrix_vector_mult(&self, rhs)
+    }
+}
+
+impl<T: Ring> Mul<&Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        assert!(rhs.is_column());
+        Vector::column(gen_matrix_vector_mult(self, &rhs.data))
+    }
+}
+
+impl<T: Ring> Mul<&Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<T: Ring> Mul<&Matrix<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        assert!(self.is_row());
+        Vector::row(gen_vector_matrix_mult(&self.data, rhs))
+    }
+}
+
+impl<T: Ring> Mul<&Matrix<T>> for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        &self * rhs
+    }
+}
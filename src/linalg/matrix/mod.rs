@@ -5,4 +5,24 @@ pub mod matrix_sub;
 pub mod matrix_neg;
 pub mod matrix_mult;
 pub mod matrix_transpose;
-pub mod matrix_row_reduction;
\ No newline at end of file
+pub mod matrix_row_reduction;
+pub mod matrix_bareiss;
+
+pub mod matrix_qr;
+pub mod matrix_subspaces;
+pub mod matrix_elementary;
+pub mod matrix_elimination_trace;
+pub mod matrix_iter;
+pub mod matrix_hadamard;
+pub mod matrix_elementwise;
+pub mod matrix_scaler_mult;
+pub mod matrix_scaler_div;
+pub mod matrix_vector_mult;
+pub mod matrix_lu;
+pub mod matrix_norm;
+pub mod matrix_predicates;
+pub mod matrix_functions;
+pub mod matrix_characteristic;
+pub mod matrix_eigen;
+pub mod matrix_normal_form;
+pub mod matrix_canonical_form;
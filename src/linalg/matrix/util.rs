@@ -1,10 +1,10 @@
-use crate::{core::number::Number, linalg::matrix::matrix::Matrix};
+use crate::{core::number::Ring, linalg::matrix::matrix::Matrix};
 
-impl<T: Number> Matrix<T> {
+impl<T: Ring> Matrix<T> {
     pub fn zeroes(rows: usize, cols: usize) -> Self {
         Self { 
-            rows: rows,
-            cols: cols,
+            rows,
+            cols,
             data: vec![T::zero(); rows * cols] 
         }
     }
@@ -16,4 +16,88 @@ impl<T: Number> Matrix<T> {
         }
         m
     }
-}
\ No newline at end of file
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![value; rows * cols]
+        }
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> Self {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Self { rows, cols, data }
+    }
+
+    pub fn from_rows(rows: &[Vec<T>]) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows.iter() {
+            assert_eq!(row.len(), cols);
+            data.extend_from_slice(row);
+        }
+        Self { rows: rows.len(), cols, data }
+    }
+
+    pub fn from_cols(cols: &[Vec<T>]) -> Self {
+        let rows = cols.first().map_or(0, |col| col.len());
+        for col in cols.iter() {
+            assert_eq!(col.len(), rows);
+        }
+        Self::from_fn(rows, cols.len(), |i, j| cols[j][i])
+    }
+
+    pub fn from_diagonal(diagonal: &[T]) -> Self {
+        let mut m = Self::zeroes(diagonal.len(), diagonal.len());
+        for (i, val) in diagonal.iter().enumerate() {
+            m[(i, i)] = *val;
+        }
+        m
+    }
+
+    // Assembles a matrix from a grid of blocks. Blocks in the same block row must have the
+    // same number of rows and blocks in the same block column the same number of columns.
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Self {
+        let Some(first_row) = blocks.first() else {
+            return Self::zeroes(0, 0);
+        };
+        let block_cols: Vec<usize> = first_row.iter().map(|b| b.cols).collect();
+        let cols = block_cols.iter().sum();
+        let mut rows = 0;
+        for block_row in blocks.iter() {
+            assert_eq!(block_row.len(), block_cols.len());
+            let height = block_row.first().map_or(0, |b| b.rows);
+            for (block, width) in block_row.iter().zip(block_cols.iter()) {
+                assert_eq!(block.rows, height);
+                assert_eq!(block.cols, *width);
+            }
+            rows += height;
+        }
+        let mut data = Vec::with_capacity(rows * cols);
+        for block_row in blocks.iter() {
+            let height = block_row.first().map_or(0, |b| b.rows);
+            for i in 0..height {
+                for block in block_row.iter() {
+                    data.extend_from_slice(&block.data[i * block.cols..(i + 1) * block.cols]);
+                }
+            }
+        }
+        Self { rows, cols, data }
+    }
+
+    pub fn hstack(matrices: &[&Matrix<T>]) -> Self {
+        Self::block(&[matrices])
+    }
+
+    pub fn vstack(matrices: &[&Matrix<T>]) -> Self {
+        let blocks: Vec<[&Matrix<T>; 1]> = matrices.iter().map(|m| [*m]).collect();
+        let block_rows: Vec<&[&Matrix<T>]> = blocks.iter().map(|b| b.as_slice()).collect();
+        Self::block(&block_rows)
+    }
+}
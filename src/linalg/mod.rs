@@ -1,3 +1,14 @@
+// The u_ functions in these modules skip the shape and bounds asserts of their safe
+// counterparts, and are sound exactly when those asserts would pass. Each module is also
+// named after the type it holds, as in linalg::matrix::matrix::Matrix.
+#[allow(clippy::missing_safety_doc, clippy::module_inception)]
 pub mod matrix;
+#[allow(clippy::missing_safety_doc, clippy::module_inception)]
 pub mod vector;
-pub mod solvers;
\ No newline at end of file
+#[allow(clippy::missing_safety_doc)]
+pub mod solvers;
+#[allow(clippy::missing_safety_doc, clippy::module_inception)]
+pub mod bit_matrix;
+pub mod permutation;
+#[allow(clippy::module_inception)]
+pub mod polynomial;
@@ -0,0 +1,186 @@
+use std::{fmt, ops::Mul};
+
+use crate::{core::number::Ring, linalg::matrix::{matrix::Matrix, matrix_elementary::RowOp}};
+
+// Position i of a permuted sequence holds element indices[i] of the original, so the
+// dense form P has P[(i, indices[i])] = 1 and P * A permutes the rows of A.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    pub indices: Vec<usize>,
+}
+
+impl Permutation {
+    pub fn identity(size: usize) -> Self {
+        Self { indices: (0..size).collect() }
+    }
+
+    pub fn from_indices(indices: Vec<usize>) -> Self {
+        let mut seen = vec![false; indices.len()];
+        for i in indices.iter() {
+            assert!(*i < indices.len());
+            assert!(!seen[*i]);
+            seen[*i] = true;
+        }
+        Self { indices }
+    }
+
+    pub fn transposition(size: usize, i: usize, j: usize) -> Self {
+        let mut p = Self::identity(size);
+        p.swap(i, j);
+        p
+    }
+
+    // The row permutation performed by the swaps in a sequence of row operations.
+    pub fn from_row_ops<T: Ring>(ops: &[RowOp<T>], size: usize) -> Self {
+        let mut p = Self::identity(size);
+        for op in ops.iter() {
+            if let RowOp::Swap(i, j) = op {
+                p.swap(*i, *j);
+            }
+        }
+        p
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.indices.iter().enumerate().all(|(i, j)| i == *j)
+    }
+
+    // Records a swap of positions i and j, as performed by swap_rows.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.indices.swap(i, j);
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut indices = vec![0; self.len()];
+        for (i, j) in self.indices.iter().enumerate() {
+            indices[*j] = i;
+        }
+        Self { indices }
+    }
+
+    // The permutation whose dense form is self * other, applying other first.
+    pub fn compose(&self, other: &Permutation) -> Self {
+        assert_eq!(self.len(), other.len());
+        Self { indices: self.indices.iter().map(|i| other.indices[*i]).collect() }
+    }
+
+    // The orbits of i -> indices[i], fixed points included.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.len()];
+        let mut cycles = Vec::new();
+        for start in 0..self.len() {
+            if visited[start] {
+                continue;
+            }
+            let mut cycle = Vec::new();
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                cycle.push(i);
+                i = self.indices[i];
+            }
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    pub fn is_even(&self) -> bool {
+        (self.len() - self.cycles().len()).is_multiple_of(2)
+    }
+
+    pub fn sign<T: Ring>(&self) -> T {
+        if self.is_even() { T::one() } else { -T::one() }
+    }
+
+    pub fn apply_vec<T: Copy>(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(self.len(), v.len());
+        self.indices.iter().map(|i| v[*i]).collect()
+    }
+
+    pub fn apply_vec_inplace<T>(&self, v: &mut [T]) {
+        assert_eq!(self.len(), v.len());
+        for cycle in self.cycles() {
+            for pair in cycle.windows(2) {
+                v.swap(pair[0], pair[1]);
+            }
+        }
+    }
+
+    pub fn apply_rows<T: Ring>(&self, a: &Matrix<T>) -> Matrix<T> {
+        let mut des = a.clone();
+        self.apply_rows_inplace(&mut des);
+        des
+    }
+
+    pub fn apply_rows_inplace<T: Ring>(&self, a: &mut Matrix<T>) {
+        assert!(a.well_formed());
+        assert_eq!(self.len(), a.rows);
+        for cycle in self.cycles() {
+            for pair in cycle.windows(2) {
+                unsafe { a.u_swap_rows(pair[0], pair[1], a.cols) };
+            }
+        }
+    }
+
+    // Column j of the result is column indices[j] of a, which is a * P^T.
+    pub fn apply_cols<T: Ring>(&self, a: &Matrix<T>) -> Matrix<T> {
+        let mut des = a.clone();
+        self.apply_cols_inplace(&mut des);
+        des
+    }
+
+    pub fn apply_cols_inplace<T: Ring>(&self, a: &mut Matrix<T>) {
+        assert!(a.well_formed());
+        assert_eq!(self.len(), a.cols);
+        for cycle in self.cycles() {
+            for pair in cycle.windows(2) {
+                unsafe { a.u_swap_cols(pair[0], pair[1], a.rows, a.cols) };
+            }
+        }
+    }
+
+    pub fn to_matrix<T: Ring>(&self) -> Matrix<T> {
+        let mut m = Matrix::zeroes(self.len(), self.len());
+        for (i, j) in self.indices.iter().enumerate() {
+            m[(i, *j)] = T::one();
+        }
+        m
+    }
+}
+
+impl Mul for &Permutation {
+    type Output = Permutation;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl fmt::Display for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut wrote = false;
+        for cycle in self.cycles().iter().filter(|cycle| cycle.len() > 1) {
+            write!(f, "(")?;
+            for (i, index) in cycle.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", index)?;
+            }
+            write!(f, ")")?;
+            wrote = true;
+        }
+        if !wrote {
+            write!(f, "()")?;
+        }
+        Ok(())
+    }
+}
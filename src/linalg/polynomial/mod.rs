@@ -0,0 +1,3 @@
+pub mod polynomial;
+pub mod polynomial_arith;
+pub mod polynomial_roots;
\ No newline at end of file
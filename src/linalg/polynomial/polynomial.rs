@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::{core::number::{Number, Ring}, linalg::matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult}};
+
+// Coefficients are stored lowest degree first, coeffs[i] multiplying x^i, with no trailing
+// zeros; the zero polynomial has no coefficients at all.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial<T: Ring> {
+    coeffs: Vec<T>,
+}
+
+impl<T: Ring> Polynomial<T> {
+    pub fn new(coeffs: Vec<T>) -> Self {
+        let mut p = Self { coeffs };
+        p.trim();
+        p
+    }
+
+    // Coefficients given highest degree first, as the polynomial is written.
+    pub fn from_highest(coeffs: &[T]) -> Self {
+        Self::new(coeffs.iter().rev().copied().collect())
+    }
+
+    pub fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        Self::constant(T::one())
+    }
+
+    pub fn constant(c: T) -> Self {
+        Self::new(vec![c])
+    }
+
+    // The polynomial x.
+    pub fn x() -> Self {
+        Self::monomial(T::one(), 1)
+    }
+
+    // c * x^degree.
+    pub fn monomial(c: T, degree: usize) -> Self {
+        let mut coeffs = vec![T::zero(); degree + 1];
+        coeffs[degree] = c;
+        Self::new(coeffs)
+    }
+
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    // The coefficient of x^i, zero beyond the degree.
+    pub fn coefficient(&self, i: usize) -> T {
+        self.coeffs.get(i).copied().unwrap_or(T::zero())
+    }
+
+    // None for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    pub fn leading_coefficient(&self) -> T {
+        self.coeffs.last().copied().unwrap_or(T::zero())
+    }
+
+    pub fn is_monic(&self) -> bool {
+        self.leading_coefficient() == T::one()
+    }
+
+    // Horner's rule.
+    pub fn eval(&self, x: T) -> T {
+        self.coeffs.iter().rev().fold(T::zero(), |acc, c| acc * x + *c)
+    }
+
+    // p(A) by Horner's rule, one matrix product per degree.
+    pub fn eval_matrix(&self, a: &Matrix<T>) -> Matrix<T> {
+        assert!(a.well_formed());
+        assert!(a.square());
+        let size = a.rows;
+        let mut result = Matrix::zeroes(size, size);
+        for c in self.coeffs.iter().rev() {
+            result = gen_matrix_mult(&result, a);
+            for i in 0..size {
+                result[(i, i)] += *c;
+            }
+        }
+        result
+    }
+
+    pub fn derivative(&self) -> Self {
+        let mut n = T::zero();
+        let coeffs = self.coeffs.iter().skip(1).map(|c| {
+            n += T::one();
+            n * *c
+        }).collect();
+        Self::new(coeffs)
+    }
+
+    fn trim(&mut self) {
+        while self.coeffs.last().is_some_and(|c| *c == T::zero()) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+impl<T: Number> Polynomial<T> {
+
+    // The n x n matrix with ones on the subdiagonal and -c_0 / c_n, ..., -c_(n-1) / c_n down
+    // the last column, whose eigenvalues are the roots of p.
+    pub fn companion_matrix(&self) -> Matrix<T> {
+        let n = self.degree().expect("the zero polynomial has no companion matrix");
+        let lead = self.leading_coefficient();
+        Matrix::from_fn(n, n, |row, col| {
+            if col == n - 1 {
+                -self.coefficient(row) / lead
+            } else if row == col + 1 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+// Written highest degree first, e.g. x^3 - 2x + 1.
+impl<T: Ring + fmt::Display> fmt::Display for Polynomial<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (i, c) in self.coeffs.iter().enumerate().rev() {
+            if *c == T::zero() {
+                continue;
+            }
+            let negative = *c < T::zero();
+            let c = if negative { -*c } else { *c };
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+                (true, false) => {},
+            }
+            first = false;
+            if c != T::one() || i == 0 {
+                write!(f, "{}", c)?;
+            }
+            match i {
+                0 => {},
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", i)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{core::number::{Number, Ring}, linalg::polynomial::polynomial::Polynomial};
+
+impl<T: Ring> Polynomial<T> {
+
+    pub fn scale(&self, a: T) -> Self {
+        Self::new(self.coeffs().iter().map(|c| a * *c).collect())
+    }
+}
+
+impl<T: Number> Polynomial<T> {
+
+    // Long division giving (q, r) with self = q * divisor + r and deg r < deg divisor.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero());
+        let divisor_degree = divisor.coeffs().len() - 1;
+        let lead_inverse = divisor.leading_coefficient().inverse();
+        let mut rem = self.coeffs().to_vec();
+        if rem.len() <= divisor_degree {
+            return (Self::zero(), self.clone());
+        }
+        let mut quotient = vec![T::zero(); rem.len() - divisor_degree];
+        for shift in (0..quotient.len()).rev() {
+            let factor = rem[shift + divisor_degree] * lead_inverse;
+            quotient[shift] = factor;
+            for (i, c) in divisor.coeffs().iter().enumerate() {
+                rem[shift + i] -= factor * *c;
+            }
+        }
+        rem.truncate(divisor_degree);
+        (Self::new(quotient), Self::new(rem))
+    }
+
+    // Scales to a leading coefficient of one; the zero polynomial stays zero.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self.scale(self.leading_coefficient().inverse())
+    }
+
+    // The monic greatest common divisor by Euclid's algorithm, zero only when both are zero.
+    // Over floats the remainders rarely vanish exactly, so this is meant for exact fields.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    // The monic least common multiple.
+    pub fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        (self * other).div_rem(&self.gcd(other)).0.monic()
+    }
+}
+
+impl<T: Ring> Add for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coeffs().len().max(rhs.coeffs().len());
+        Polynomial::new((0..len).map(|i| self.coefficient(i) + rhs.coefficient(i)).collect())
+    }
+}
+
+impl<T: Ring> Add for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<T: Ring> Sub for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let len = self.coeffs().len().max(rhs.coeffs().len());
+        Polynomial::new((0..len).map(|i| self.coefficient(i) - rhs.coefficient(i)).collect())
+    }
+}
+
+impl<T: Ring> Sub for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<T: Ring> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Self::Output {
+        Polynomial::new(self.coeffs().iter().map(|c| -*c).collect())
+    }
+}
+
+impl<T: Ring> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<T: Ring> Mul for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut coeffs = vec![T::zero(); self.coeffs().len() + rhs.coeffs().len() - 1];
+        for (i, a) in self.coeffs().iter().enumerate() {
+            for (j, b) in rhs.coeffs().iter().enumerate() {
+                coeffs[i + j] += *a * *b;
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<T: Ring> Mul for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
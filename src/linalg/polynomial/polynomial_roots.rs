@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+
+use crate::{core::{complex::Complex, number::{int, RealNumber, Ring}}, linalg::{matrix::matrix_norm::machine_epsilon, polynomial::polynomial::Polynomial}};
+
+const NEWTON_MAX_STEPS: usize = 50;
+
+impl<T: Ring> Polynomial<T> {
+
+    // The monic (x - r_1) * (x - r_2) * ... * (x - r_n).
+    pub fn from_roots(roots: &[T]) -> Self {
+        roots.iter().fold(Self::one(), |acc, r| &acc * &Self::new(vec![-*r, T::one()]))
+    }
+}
+
+impl<T: RealNumber> Polynomial<T> {
+
+    // The real monic polynomial with these roots, which must come in conjugate pairs; the
+    // imaginary parts left in the product are round-off and are dropped.
+    pub fn from_complex_roots(roots: &[Complex<T>]) -> Self {
+        Self::new(Polynomial::from_roots(roots).coeffs().iter().map(|c| c.re).collect())
+    }
+
+    // Horner's rule at a complex point.
+    pub fn eval_complex(&self, z: Complex<T>) -> Complex<T> {
+        let zero = Complex { re: T::zero(), im: T::zero() };
+        self.coeffs().iter().rev().fold(zero, |acc, c| acc * z + Complex { re: *c, im: T::zero() })
+    }
+
+    // Every complex root, repeated by multiplicity and ordered by real then imaginary part.
+    // None if the eigenvalue iteration fails to converge.
+    pub fn roots(&self) -> Option<Vec<Complex<T>>> {
+        let found = self.roots_with_multiplicity()?;
+        Some(found.into_iter().flat_map(|(root, multiplicity)| std::iter::repeat_n(root, multiplicity)).collect())
+    }
+
+    // The distinct roots with their multiplicities, or None if the eigenvalue iteration fails
+    // to converge. The roots start as the eigenvalues of the companion matrix, each polished by
+    // Newton's method. Round-off scatters an m-fold root into m eigenvalues about eps^(1/m)
+    // apart, so each eigenvalue is tried with its m - 1 nearest neighbours as an m-fold root,
+    // largest m first, whenever they lie within that distance. The cluster's centre is refined
+    // as the simple root of p^(m-1) and kept when it stays inside the cluster and
+    // p, p', ..., p^(m-1) all vanish there to round-off.
+    pub fn roots_with_multiplicity(&self) -> Option<Vec<(Complex<T>, usize)>> {
+        assert!(!self.is_zero());
+        let zero = Complex { re: T::zero(), im: T::zero() };
+        let mut found = Vec::new();
+        // Roots at zero are exact; dividing them out leaves p(0) != 0.
+        let zero_roots = self.coeffs().iter().take_while(|c| **c == T::zero()).count();
+        if zero_roots > 0 {
+            found.push((zero, zero_roots));
+        }
+        let p = Self::new(self.coeffs()[zero_roots..].to_vec());
+        if p.degree().unwrap() > 0 {
+            let derivatives = successive_derivatives(&p);
+            let mut pool: Vec<Complex<T>> = p.companion_matrix().eigenvalues()?
+                .into_iter()
+                .map(|z| polish(&p, &derivatives[1], z))
+                .collect();
+            while let Some(seed) = pool.pop() {
+                pool.sort_by(|a, b| (*a - seed).abs().partial_cmp(&(*b - seed).abs()).unwrap_or(Ordering::Equal));
+                let scale = max(T::one(), seed.abs());
+                let cluster = (2..=pool.len() + 1).rev().find_map(|m| {
+                    let radius = cluster_radius::<T>(m) * scale;
+                    if (pool[m - 2] - seed).abs() > radius {
+                        return None;
+                    }
+                    let mut cluster = vec![seed];
+                    cluster.extend_from_slice(&pool[..m - 1]);
+                    multiple_root(&derivatives, &cluster, radius).map(|root| (root, m))
+                });
+                match cluster {
+                    Some((root, m)) => {
+                        pool.drain(..m - 1);
+                        found.push((root, m));
+                    },
+                    None => found.push((seed, 1)),
+                }
+            }
+        }
+        found.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        Some(found)
+    }
+}
+
+// p, p', p'', ..., up to the constant derivative.
+fn successive_derivatives<T: RealNumber>(p: &Polynomial<T>) -> Vec<Polynomial<T>> {
+    let mut derivatives = vec![p.clone()];
+    while derivatives.last().unwrap().degree().is_some_and(|degree| degree > 0) {
+        let next = derivatives.last().unwrap().derivative();
+        derivatives.push(next);
+    }
+    derivatives
+}
+
+// Newton's method from z, stopping once a step no longer reduces |p|.
+fn polish<T: RealNumber>(p: &Polynomial<T>, dp: &Polynomial<T>, mut z: Complex<T>) -> Complex<T> {
+    let eps = machine_epsilon::<T>();
+    let mut value = p.eval_complex(z).abs();
+    for _ in 0..NEWTON_MAX_STEPS {
+        if value == T::zero() {
+            break;
+        }
+        let slope = dp.eval_complex(z);
+        if slope.abs() == T::zero() {
+            break;
+        }
+        let step = p.eval_complex(z) / slope;
+        let next = z - step;
+        let next_value = p.eval_complex(next).abs();
+        if next_value >= value {
+            break;
+        }
+        z = next;
+        value = next_value;
+        if step.abs() <= eps * z.abs() {
+            break;
+        }
+    }
+    z
+}
+
+// How far apart round-off can scatter the approximations of an m-fold root of unit size.
+fn cluster_radius<T: RealNumber>(m: usize) -> T {
+    machine_epsilon::<T>().power(T::one() / int::<T>(m)) * int::<T>(16)
+}
+
+// The m-fold root the cluster of m approximations represents, if there is one. Each of
+// p, p', ..., p^(m-1) must vanish to within the rounding error of evaluating it by Horner's
+// rule, 2 * n * eps * sum |c_i| * |z|^i.
+fn multiple_root<T: RealNumber>(derivatives: &[Polynomial<T>], cluster: &[Complex<T>], radius: T) -> Option<Complex<T>> {
+    let m = cluster.len();
+    if m >= derivatives.len() {
+        return None;
+    }
+    let centre = centroid(cluster);
+    let root = polish(&derivatives[m - 1], &derivatives[m], centre);
+    if (root - centre).abs() > radius {
+        return None;
+    }
+    let eps = machine_epsilon::<T>();
+    let vanishes = derivatives[..m].iter().all(|d| {
+        let tol = int::<T>(2 * d.coeffs().len()) * eps;
+        let bound = d.coeffs().iter().rev().fold(T::zero(), |acc, c| acc * root.abs() + c.abs());
+        d.eval_complex(root).abs() <= tol * bound
+    });
+    if vanishes { Some(root) } else { None }
+}
+
+fn centroid<T: RealNumber>(points: &[Complex<T>]) -> Complex<T> {
+    let zero = Complex { re: T::zero(), im: T::zero() };
+    let sum = points.iter().fold(zero, |acc, z| acc + *z);
+    let n = int::<T>(points.len());
+    Complex { re: sum.re / n, im: sum.im / n }
+}
+
+fn max<T: RealNumber>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
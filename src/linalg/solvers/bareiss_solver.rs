@@ -0,0 +1,43 @@
+use crate::{core::number::IntegralDomain, linalg::matrix::matrix::Matrix};
+
+// Solutions are returned as numerators over a common denominator so integer systems stay exact.
+pub fn solve_linear_system_bareiss<T: IntegralDomain>(aug_matrix: &Matrix<T>) -> Option<(Vec<T>, T)> {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    unsafe { u_solve_linear_system_bareiss(aug_matrix, aug_matrix.rows, aug_matrix.cols) }
+}
+
+pub unsafe fn u_solve_linear_system_bareiss<T: IntegralDomain>(aug_matrix: &Matrix<T>, rows: usize, cols: usize) -> Option<(Vec<T>, T)> {
+    let matrix_copy = &mut aug_matrix.clone();
+    let mut des_vector = vec![T::zero(); cols - 1];
+    unsafe { u_isolve_linear_system_bareiss(matrix_copy, des_vector.as_mut_ptr(), rows, cols) }
+        .map(|den| (des_vector, den))
+}
+
+pub fn isolve_linear_system_bareiss<T: IntegralDomain>(aug_matrix: &mut Matrix<T>, answer_des: &mut Vec<T>) -> Option<T> {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    assert_eq!(answer_des.len(), aug_matrix.cols - 1);
+    unsafe { u_isolve_linear_system_bareiss(aug_matrix, answer_des.as_mut_ptr(), aug_matrix.rows, aug_matrix.cols) }
+}
+
+pub unsafe fn u_isolve_linear_system_bareiss<T: IntegralDomain>(aug_matrix: &mut Matrix<T>, answer_des: *mut T, rows: usize, cols: usize) -> Option<T> {
+    let pivots = unsafe { aug_matrix.u_bareiss_reduced_row_echelon_form(rows, cols) };
+    let last_col = cols - 1;
+    if pivots.last() == Some(&last_col) {
+        return None;
+    }
+    let denominator = match pivots.last() {
+        Some(&col) => unsafe { *aug_matrix.get_loc(pivots.len() - 1, col) },
+        None => T::one(),
+    };
+    unsafe {
+        for col in 0..last_col {
+            *answer_des.add(col) = T::zero();
+        }
+        for (row, col) in pivots.iter().enumerate() {
+            *answer_des.add(*col) = *aug_matrix.get_loc(row, last_col);
+        }
+    }
+    Some(denominator)
+}
@@ -0,0 +1,25 @@
+use crate::linalg::bit_matrix::bit_matrix::BitMatrix;
+
+pub fn solve_linear_system_bit_matrix(aug_matrix: &BitMatrix) -> Option<Vec<bool>> {
+    assert!(aug_matrix.cols > 0);
+    let matrix_copy = &mut aug_matrix.clone();
+    unsafe { u_isolve_linear_system_bit_matrix(matrix_copy, aug_matrix.rows, aug_matrix.cols) }
+}
+
+pub fn isolve_linear_system_bit_matrix(aug_matrix: &mut BitMatrix) -> Option<Vec<bool>> {
+    assert!(aug_matrix.cols > 0);
+    unsafe { u_isolve_linear_system_bit_matrix(aug_matrix, aug_matrix.rows, aug_matrix.cols) }
+}
+
+pub unsafe fn u_isolve_linear_system_bit_matrix(aug_matrix: &mut BitMatrix, rows: usize, cols: usize) -> Option<Vec<bool>> {
+    let pivots = unsafe { aug_matrix.u_reduced_row_echelon_form(rows, cols) };
+    let last_col = cols - 1;
+    if pivots.last() == Some(&last_col) {
+        return None;
+    }
+    let mut answer = vec![false; last_col];
+    for (row, col) in pivots.iter().enumerate() {
+        answer[*col] = unsafe { aug_matrix.u_get(row, last_col) };
+    }
+    Some(answer)
+}
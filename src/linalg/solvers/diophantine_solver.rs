@@ -0,0 +1,67 @@
+use crate::linalg::matrix::{matrix::Matrix, matrix_normal_form::{smith_wide, IntegerOverflow}};
+
+// Every integer solution is particular plus an integer combination of the kernel vectors,
+// which form a basis of the integer null space.
+pub struct IntegerSolution {
+    pub particular: Vec<i64>,
+    pub kernel: Vec<Vec<i64>>,
+}
+
+impl IntegerSolution {
+
+    pub fn is_unique(&self) -> bool {
+        self.kernel.is_empty()
+    }
+
+    // particular + params[0] * kernel[0] + params[1] * kernel[1] + ...
+    pub fn sample(&self, params: &[i64]) -> Vec<i64> {
+        assert_eq!(params.len(), self.kernel.len());
+        let mut x = self.particular.clone();
+        for (param, vec) in params.iter().zip(self.kernel.iter()) {
+            x.iter_mut().zip(vec.iter()).for_each(|(x, v)| *x += param * v);
+        }
+        x
+    }
+}
+
+// Solves A * x = b over the integers from the augmented matrix [A | b], returning Ok(None)
+// when there is no integer solution, even if there is a rational one. With U * A * V = D in
+// Smith normal form, x = V * y turns the system into d_i * y_i = (U * b)_i, which is solvable
+// exactly when each d_i divides its entry and the entries past the rank are zero.
+pub fn solve_diophantine_system(aug_matrix: &Matrix<i64>) -> Result<Option<IntegerSolution>, IntegerOverflow> {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    let rows = aug_matrix.rows;
+    let unknowns = aug_matrix.cols - 1;
+    let a = Matrix::from_fn(rows, unknowns, |row, col| aug_matrix[(row, col)] as i128);
+    let b: Vec<i128> = (0..rows).map(|row| aug_matrix[(row, unknowns)] as i128).collect();
+    let (d, u, v) = smith_wide(a)?;
+    let c = checked_mult_vec(&u, &b)?;
+    let rank = d.diagonal_iter().take_while(|val| **val != 0).count();
+    if c[rank..].iter().any(|val| *val != 0) {
+        return Ok(None);
+    }
+    let mut y = vec![0; unknowns];
+    for i in 0..rank {
+        let d_i = d[(i, i)];
+        if c[i] % d_i != 0 {
+            return Ok(None);
+        }
+        y[i] = c[i] / d_i;
+    }
+    let particular = checked_mult_vec(&v, &y)?.into_iter().map(to_i64).collect::<Result<_, _>>()?;
+    let kernel = (rank..unknowns)
+        .map(|col| (0..unknowns).map(|row| to_i64(v[(row, col)])).collect::<Result<_, _>>())
+        .collect::<Result<_, _>>()?;
+    Ok(Some(IntegerSolution { particular, kernel }))
+}
+
+fn checked_mult_vec(a: &Matrix<i128>, x: &[i128]) -> Result<Vec<i128>, IntegerOverflow> {
+    (0..a.rows).map(|row| {
+        (0..a.cols).try_fold(0i128, |acc, col| a[(row, col)].checked_mul(x[col]).and_then(|val| acc.checked_add(val)).ok_or(IntegerOverflow))
+    }).collect()
+}
+
+fn to_i64(val: i128) -> Result<i64, IntegerOverflow> {
+    i64::try_from(val).map_err(|_| IntegerOverflow)
+}
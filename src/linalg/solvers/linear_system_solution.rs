@@ -1,8 +1,175 @@
-use crate::core::number::Number;
+use std::fmt;
 
+use crate::{core::number::Number, linalg::matrix::{matrix::Matrix, matrix_row_reduction::magnitude}};
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum LinearSystemSolution<T: Number> {
     AffineBasis(Vec<T>, Vec<Vec<T>>),
     HomogenousBasis(Vec<Vec<T>>),
     Solution(Vec<T>),
     None,
-}
\ No newline at end of file
+}
+
+impl<T: Number> LinearSystemSolution<T> {
+
+    pub fn is_consistent(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    pub fn is_unique(&self) -> bool {
+        matches!(self, Self::Solution(_))
+    }
+
+    // One solution of the system, with every free parameter zero.
+    pub fn particular(&self) -> Option<Vec<T>> {
+        match self {
+            Self::AffineBasis(particular, _) => Some(particular.clone()),
+            Self::HomogenousBasis(basis) => Some(vec![T::zero(); basis.first().map_or(0, |v| v.len())]),
+            Self::Solution(solution) => Some(solution.clone()),
+            Self::None => None,
+        }
+    }
+
+    // The null space directions the solution set extends along; empty unless there are free variables.
+    pub fn basis(&self) -> &[Vec<T>] {
+        match self {
+            Self::AffineBasis(_, basis) | Self::HomogenousBasis(basis) => basis,
+            Self::Solution(_) | Self::None => &[],
+        }
+    }
+
+    // The dimension of the solution set, or None when it is empty.
+    pub fn dimension(&self) -> Option<usize> {
+        if self.is_consistent() { Some(self.basis().len()) } else { None }
+    }
+
+    // The solution particular + params[0] * basis[0] + params[1] * basis[1] + ...
+    pub fn sample(&self, params: &[T]) -> Option<Vec<T>> {
+        let mut solution = self.particular()?;
+        let basis = self.basis();
+        assert_eq!(params.len(), basis.len());
+        for (param, vec) in params.iter().zip(basis.iter()) {
+            solution.iter_mut().zip(vec.iter()).for_each(|(x, v)| *x += *param * *v);
+        }
+        Some(solution)
+    }
+
+    pub fn verify(&self, aug_matrix: &Matrix<T>) -> bool {
+        self.verify_tol(aug_matrix, T::zero())
+    }
+
+    // Checks this is the complete solution set of the augmented system: the particular
+    // solution satisfies it, the basis is an independent set of null space vectors and
+    // there are as many of them as free variables. None is checked by confirming the system
+    // is inconsistent. Residual entries within tol of zero are accepted.
+    pub fn verify_tol(&self, aug_matrix: &Matrix<T>, tol: T) -> bool {
+        assert!(aug_matrix.well_formed());
+        assert!(aug_matrix.cols > 0);
+        let unknowns = aug_matrix.cols - 1;
+        let mut reduced = aug_matrix.clone();
+        let pivots = reduced.reduced_row_echelon_form_tol(tol);
+        let consistent = pivots.last() != Some(&unknowns);
+        let Some(particular) = self.particular() else {
+            return !consistent;
+        };
+        let basis = self.basis();
+        if !consistent || particular.len() != unknowns || basis.iter().any(|v| v.len() != unknowns) {
+            return false;
+        }
+        if basis.len() != unknowns - pivots.len() {
+            return false;
+        }
+        let satisfies = |x: &[T], homogeneous: bool| aug_matrix.rows_iter().all(|row| {
+            let rhs = if homogeneous { T::zero() } else { row[unknowns] };
+            let lhs = row[..unknowns].iter().zip(x.iter()).fold(T::zero(), |acc, (a, x)| acc + *a * *x);
+            magnitude(lhs - rhs) <= tol
+        });
+        if !satisfies(&particular, false) || !basis.iter().all(|v| satisfies(v, true)) {
+            return false;
+        }
+        basis.is_empty() || Matrix::from_rows(basis).reduced_row_echelon_form_tol(tol).len() == basis.len()
+    }
+}
+
+// Prints the parametric form, e.g. x = [1, 0, 2] + t₁·[-2, 1, 0] + t₂·[-3, 0, 1].
+impl<T: Number + fmt::Display> fmt::Display for LinearSystemSolution<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(particular) = self.particular() else {
+            return write!(f, "no solution");
+        };
+        write!(f, "x = ")?;
+        let basis = self.basis();
+        if !matches!(self, Self::HomogenousBasis(_)) {
+            write_vec(f, &particular)?;
+        }
+        for (i, vec) in basis.iter().enumerate() {
+            if i > 0 || !matches!(self, Self::HomogenousBasis(_)) {
+                write!(f, " + ")?;
+            }
+            write!(f, "t{}·", subscript(i + 1))?;
+            write_vec(f, vec)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_vec<T: fmt::Display>(f: &mut fmt::Formatter<'_>, vals: &[T]) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, val) in vals.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", val)?;
+    }
+    write!(f, "]")
+}
+
+fn subscript(n: usize) -> String {
+    n.to_string().chars().map(|digit| char::from_u32('₀' as u32 + digit.to_digit(10).unwrap()).unwrap()).collect()
+}
+
+// The solutions of a matrix equation, one LinearSystemSolution per right hand side column.
+// When transposed is set the equation was X * A = B and each entry describes a row of X.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixSystemSolution<T: Number> {
+    pub columns: Vec<LinearSystemSolution<T>>,
+    pub transposed: bool,
+}
+
+impl<T: Number> MatrixSystemSolution<T> {
+
+    pub fn is_consistent(&self, col: usize) -> bool {
+        self.columns[col].is_consistent()
+    }
+
+    pub fn consistent_columns(&self) -> Vec<usize> {
+        (0..self.columns.len()).filter(|col| self.is_consistent(*col)).collect()
+    }
+
+    pub fn inconsistent_columns(&self) -> Vec<usize> {
+        (0..self.columns.len()).filter(|col| !self.is_consistent(*col)).collect()
+    }
+
+    pub fn all_consistent(&self) -> bool {
+        (0..self.columns.len()).all(|col| self.is_consistent(col))
+    }
+
+    // X when every column has exactly one solution.
+    pub fn solution(&self) -> Option<Matrix<T>> {
+        let parts = self.columns.iter().map(|column| match column {
+            LinearSystemSolution::Solution(x) => Some(x.clone()),
+            _ => None,
+        }).collect::<Option<Vec<_>>>()?;
+        Some(self.assemble(&parts))
+    }
+
+    // One X, with every free variable zero, when every column is consistent.
+    pub fn particular(&self) -> Option<Matrix<T>> {
+        let parts = self.columns.iter().map(|column| column.particular()).collect::<Option<Vec<_>>>()?;
+        Some(self.assemble(&parts))
+    }
+
+    fn assemble(&self, parts: &[Vec<T>]) -> Matrix<T> {
+        if self.transposed { Matrix::from_rows(parts) } else { Matrix::from_cols(parts) }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{core::number::Number, linalg::{matrix::{matrix::Matrix, matrix_row_reduction::magnitude, matrix_transpose::gen_matrix_transpose}, solvers::{linear_system_solution::{LinearSystemSolution, MatrixSystemSolution}, row_reduction_solver::u_solution_from_pivots_rhs}}};
+
+// Solves A * X = B for every column of B with a single elimination of [A | B].
+pub fn solve_matrix<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> MatrixSystemSolution<T> {
+    solve_matrix_tol(a, b, T::zero())
+}
+
+pub fn solve_matrix_tol<T: Number>(a: &Matrix<T>, b: &Matrix<T>, tol: T) -> MatrixSystemSolution<T> {
+    assert!(a.well_formed());
+    assert!(b.well_formed());
+    assert_eq!(a.rows, b.rows);
+    let mut aug_matrix = Matrix::hstack(&[a, b]);
+    unsafe { u_isolve_matrix_tol(&mut aug_matrix, a.rows, a.cols, b.cols, tol) }
+}
+
+// Solves X * A = B through A^T * X^T = B^T; each solution describes a row of X.
+pub fn solve_matrix_right<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> MatrixSystemSolution<T> {
+    solve_matrix_right_tol(a, b, T::zero())
+}
+
+pub fn solve_matrix_right_tol<T: Number>(a: &Matrix<T>, b: &Matrix<T>, tol: T) -> MatrixSystemSolution<T> {
+    assert_eq!(a.cols, b.cols);
+    let mut solution = solve_matrix_tol(&gen_matrix_transpose(a), &gen_matrix_transpose(b), tol);
+    solution.transposed = true;
+    solution
+}
+
+// Reduces [A | B] in place, pivoting only within the unknowns columns of A. A column of B is
+// consistent when it is zero in every row below the last pivot.
+pub unsafe fn u_isolve_matrix_tol<T: Number>(aug_matrix: &mut Matrix<T>, rows: usize, unknowns: usize, rhs_cols: usize, tol: T) -> MatrixSystemSolution<T> {
+    let cols = unknowns + rhs_cols;
+    let pivots = unsafe { aug_matrix.u_reduced_row_echelon_form_leading(rows, cols, unknowns, tol) };
+    let columns = (unknowns..cols).map(|rhs_col| {
+        let consistent = (pivots.len()..rows).all(|row| magnitude(unsafe { *aug_matrix.get_loc(row, rhs_col) }) <= tol);
+        if consistent {
+            unsafe { u_solution_from_pivots_rhs(aug_matrix, &pivots, unknowns, cols, rhs_col) }
+        } else {
+            LinearSystemSolution::None
+        }
+    }).collect();
+    MatrixSystemSolution { columns, transposed: false }
+}
@@ -1,2 +1,7 @@
 pub mod linear_system_solution;
 pub mod row_reduction_solver;
+pub mod bareiss_solver;
+pub mod bit_matrix_solver;
+pub mod refinement_solver;
+pub mod matrix_equation_solver;
+pub mod diophantine_solver;
@@ -0,0 +1,110 @@
+use crate::{core::number::{int, RealNumber, Widen}, linalg::{matrix::{matrix::Matrix, matrix_lu::LuDecomposition, matrix_norm::machine_epsilon}, vector::vector_norm::gen_vector_norm_linf}};
+
+// How far a computed solution of A * x = b can be trusted, in the infinity norm.
+#[derive(Clone, Debug)]
+pub struct SolveReport<T: RealNumber> {
+    pub solution: Vec<T>,
+    // ||b - A * x||.
+    pub residual_norm: T,
+    // The smallest relative perturbation of A and b that x solves exactly,
+    // ||b - A * x|| / (||A|| * ||x|| + ||b||).
+    pub backward_error: T,
+    // A bound on ||x - x_true|| / ||x||, or None when A is not square or is singular.
+    pub forward_error_estimate: Option<T>,
+    pub refinement_steps: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RefinementOptions {
+    pub max_steps: usize,
+    // Accumulate residuals in extra precision: in f64 for an f32 solve, and as compensated
+    // double-f64 sums for an f64 solve.
+    pub extended_residuals: bool,
+}
+
+impl Default for RefinementOptions {
+    fn default() -> Self {
+        Self { max_steps: 10, extended_residuals: false }
+    }
+}
+
+// Reports on a solution obtained any other way, such as from solve_linear_system_rref.
+pub fn solve_report<T: Widen>(aug_matrix: &Matrix<T>, solution: &[T]) -> SolveReport<T> {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    assert_eq!(aug_matrix.cols - 1, solution.len());
+    let (a, b) = split_augmented(aug_matrix);
+    let lu = if a.square() { Some(a.lu()) } else { None };
+    let residual = residual(&a, &b, solution, false);
+    build_report(&a, &b, solution.to_vec(), &residual, lu.as_ref(), 0, false)
+}
+
+// Solves a square system by LU, then repeatedly solves A * d = b - A * x with the same
+// factors and adds the correction d to x, stopping once the correction is at round-off
+// level or stops shrinking by at least half. None when the coefficients are singular.
+pub fn solve_linear_system_refined<T: Widen>(aug_matrix: &Matrix<T>, options: RefinementOptions) -> Option<SolveReport<T>> {
+    assert!(aug_matrix.well_formed());
+    assert_eq!(aug_matrix.rows + 1, aug_matrix.cols);
+    let (a, b) = split_augmented(aug_matrix);
+    let lu = a.lu();
+    let mut x = lu.solve(&b)?;
+    let eps = machine_epsilon::<T>();
+    let two = T::one() + T::one();
+    let mut last_correction: Option<T> = None;
+    let mut steps = 0;
+    let mut r = residual(&a, &b, &x, options.extended_residuals);
+    while steps < options.max_steps {
+        let d = lu.solve(&r)?;
+        let d_norm = gen_vector_norm_linf(&d);
+        if last_correction.is_some_and(|last| d_norm > last / two) {
+            break;
+        }
+        x.iter_mut().zip(d.iter()).for_each(|(x, d)| *x += *d);
+        steps += 1;
+        r = residual(&a, &b, &x, options.extended_residuals);
+        if d_norm <= eps * gen_vector_norm_linf(&x) {
+            break;
+        }
+        last_correction = Some(d_norm);
+    }
+    Some(build_report(&a, &b, x, &r, Some(&lu), steps, options.extended_residuals))
+}
+
+fn split_augmented<T: RealNumber>(aug_matrix: &Matrix<T>) -> (Matrix<T>, Vec<T>) {
+    let cols = aug_matrix.cols - 1;
+    let a = Matrix::from_fn(aug_matrix.rows, cols, |row, col| aug_matrix[(row, col)]);
+    let b = (0..aug_matrix.rows).map(|row| aug_matrix[(row, cols)]).collect();
+    (a, b)
+}
+
+fn residual<T: Widen>(a: &Matrix<T>, b: &[T], x: &[T], extended: bool) -> Vec<T> {
+    a.rows_iter().zip(b.iter()).map(|(row, b)| {
+        if extended {
+            T::extended_residual(*b, row, x)
+        } else {
+            row.iter().zip(x.iter()).fold(*b, |acc, (a, x)| acc - *a * *x)
+        }
+    }).collect()
+}
+
+// The forward bound follows LAPACK's xGERFS: ||A^-1|| * (||r|| + n * eps * (||A|| * ||x|| + ||b||))
+// over ||x||, where eps is the precision r was accumulated in and covers the rounding in
+// computing r itself. Extended residuals add the rounding of x back to T.
+fn build_report<T: Widen>(a: &Matrix<T>, b: &[T], solution: Vec<T>, residual: &[T], lu: Option<&LuDecomposition<T>>, refinement_steps: usize, extended: bool) -> SolveReport<T> {
+    let residual_norm = gen_vector_norm_linf(residual);
+    let x_norm = gen_vector_norm_linf(&solution);
+    let scale = a.norm_inf() * x_norm + gen_vector_norm_linf(b);
+    let backward_error = if scale == T::zero() { T::zero() } else { residual_norm / scale };
+    let forward_error_estimate = lu.and_then(|lu| lu.inverse_norm_inf_estimate()).map(|inverse_norm| {
+        if x_norm == T::zero() {
+            return T::zero();
+        }
+        let n = int::<T>(a.cols);
+        if extended {
+            inverse_norm * (residual_norm + n * T::extended_epsilon() * scale) / x_norm + machine_epsilon::<T>()
+        } else {
+            inverse_norm * (residual_norm + n * machine_epsilon::<T>() * scale) / x_norm
+        }
+    });
+    SolveReport { solution, residual_norm, backward_error, forward_error_estimate, refinement_steps }
+}
@@ -1,13 +1,14 @@
-use crate::{core::number::Number, linalg::{matrix::matrix::Matrix, solvers::linear_system_solution::LinearSystemSolution, vector::util::non_zeroed_vec}};
+use crate::{core::number::{Number, RealNumber}, linalg::{matrix::matrix::Matrix, solvers::linear_system_solution::LinearSystemSolution, vector::util::zeroed_vec}};
 
 pub fn solve_linear_system_rref<T: Number>(aug_matrix: &Matrix<T>) -> Option<Vec<T>> {
     assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
     unsafe { u_solve_linear_system_rref(aug_matrix, aug_matrix.rows, aug_matrix.cols) }
 }
 
 pub unsafe fn u_solve_linear_system_rref<T: Number>(aug_matrix: &Matrix<T>, rows: usize, cols: usize) -> Option<Vec<T>> {
     let matrix_copy = &mut aug_matrix.clone();
-    let mut des_vector = unsafe { non_zeroed_vec::<T>(cols - 1) };
+    let mut des_vector = zeroed_vec::<T>(cols - 1);
     if unsafe { u_isolve_linear_system_rref(matrix_copy, des_vector.as_mut_ptr(), rows, cols) } {
         Some(des_vector)
     } else {
@@ -17,40 +18,61 @@ pub unsafe fn u_solve_linear_system_rref<T: Number>(aug_matrix: &Matrix<T>, rows
 
 pub fn isolve_linear_system_rref<T: Number>(aug_matrix: &mut Matrix<T>, answer_des: &mut Vec<T>) -> bool {
     assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
     assert_eq!(answer_des.len(), aug_matrix.cols - 1);
     unsafe { u_isolve_linear_system_rref(aug_matrix, answer_des.as_mut_ptr(), aug_matrix.rows, aug_matrix.cols) }
 }
 
 pub unsafe fn u_isolve_linear_system_rref<T: Number>(aug_matrix: &mut Matrix<T>, answer_des: *mut T, rows: usize, cols: usize) -> bool {
-    unsafe {
-        aug_matrix.u_reduced_row_echelon_form(rows, cols);
+    unsafe { u_isolve_linear_system_rref_tol(aug_matrix, answer_des, rows, cols, T::zero()) }
+}
+
+// The _tol solvers treat pivots and right hand side entries within tol of zero as zero,
+// so round-off neither invents pivots nor makes a consistent system look inconsistent.
+pub fn solve_linear_system_rref_tol<T: Number>(aug_matrix: &Matrix<T>, tol: T) -> Option<Vec<T>> {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    unsafe { u_solve_linear_system_rref_tol(aug_matrix, aug_matrix.rows, aug_matrix.cols, tol) }
+}
+
+pub unsafe fn u_solve_linear_system_rref_tol<T: Number>(aug_matrix: &Matrix<T>, rows: usize, cols: usize, tol: T) -> Option<Vec<T>> {
+    let matrix_copy = &mut aug_matrix.clone();
+    let mut des_vector = zeroed_vec::<T>(cols - 1);
+    if unsafe { u_isolve_linear_system_rref_tol(matrix_copy, des_vector.as_mut_ptr(), rows, cols, tol) } {
+        Some(des_vector)
+    } else {
+        None
     }
+}
+
+pub fn isolve_linear_system_rref_tol<T: Number>(aug_matrix: &mut Matrix<T>, answer_des: &mut Vec<T>, tol: T) -> bool {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    assert_eq!(answer_des.len(), aug_matrix.cols - 1);
+    unsafe { u_isolve_linear_system_rref_tol(aug_matrix, answer_des.as_mut_ptr(), aug_matrix.rows, aug_matrix.cols, tol) }
+}
+
+pub unsafe fn u_isolve_linear_system_rref_tol<T: Number>(aug_matrix: &mut Matrix<T>, answer_des: *mut T, rows: usize, cols: usize, tol: T) -> bool {
+    let pivots = unsafe { aug_matrix.u_reduced_row_echelon_form_tol(rows, cols, tol) };
     let last_col = cols - 1;
-    let mut current_col = 0;
-    let mut row_offset;
+    // A pivot in the augmented column is a row reading 0 = 1.
+    if pivots.last() == Some(&last_col) {
+        return false;
+    }
     unsafe {
-        for row in 0..rows {
-            row_offset = row * cols;
-            while *aug_matrix.get(row_offset + current_col) != T::one() {
-                *answer_des.add(current_col) = T::zero();
-                current_col += 1;
-                if current_col == last_col {
-                    if *aug_matrix.get(row_offset + current_col) != T::zero() {
-                        return false;
-                    }
-                    return true;
-                }
-            }
-            *answer_des.add(current_col) = *aug_matrix.get(row_offset + last_col);
-            current_col += 1;
+        for col in 0..last_col {
+            *answer_des.add(col) = T::zero();
+        }
+        for (row, pivot_col) in pivots.iter().enumerate() {
+            *answer_des.add(*pivot_col) = *aug_matrix.get(row * cols + last_col);
         }
     }
     true
-
 }
 
 pub fn solve_linear_system_basis_rref<T: Number>(aug_matrix: &Matrix<T>) -> LinearSystemSolution<T> {
     assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
     unsafe { u_solve_linear_system_basis_rref(aug_matrix, aug_matrix.rows, aug_matrix.cols) }
 }
 
@@ -61,73 +83,88 @@ pub unsafe fn u_solve_linear_system_basis_rref<T: Number>(aug_matrix: &Matrix<T>
 
 pub fn isolve_linear_system_basis_rref<T: Number>(aug_matrix: &mut Matrix<T>) -> LinearSystemSolution<T> {
     assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
     unsafe { u_isolve_linear_system_basis_rref(aug_matrix, aug_matrix.rows, aug_matrix.cols) }
 }
 
 pub unsafe fn u_isolve_linear_system_basis_rref<T: Number>(aug_matrix: &mut Matrix<T>, rows: usize, cols: usize) -> LinearSystemSolution<T> {
-    unsafe {
-        aug_matrix.u_reduced_row_echelon_form(rows, cols);
-    }
+    unsafe { u_isolve_linear_system_basis_rref_tol(aug_matrix, rows, cols, T::zero()) }
+}
+
+pub fn solve_linear_system_basis_rref_tol<T: Number>(aug_matrix: &Matrix<T>, tol: T) -> LinearSystemSolution<T> {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    unsafe { u_solve_linear_system_basis_rref_tol(aug_matrix, aug_matrix.rows, aug_matrix.cols, tol) }
+}
+
+pub unsafe fn u_solve_linear_system_basis_rref_tol<T: Number>(aug_matrix: &Matrix<T>, rows: usize, cols: usize, tol: T) -> LinearSystemSolution<T> {
+    let matrix_copy = &mut aug_matrix.clone();
+    unsafe { u_isolve_linear_system_basis_rref_tol(matrix_copy, rows, cols, tol) }
+}
+
+pub fn isolve_linear_system_basis_rref_tol<T: Number>(aug_matrix: &mut Matrix<T>, tol: T) -> LinearSystemSolution<T> {
+    assert!(aug_matrix.well_formed());
+    assert!(aug_matrix.cols > 0);
+    unsafe { u_isolve_linear_system_basis_rref_tol(aug_matrix, aug_matrix.rows, aug_matrix.cols, tol) }
+}
+
+pub unsafe fn u_isolve_linear_system_basis_rref_tol<T: Number>(aug_matrix: &mut Matrix<T>, rows: usize, cols: usize, tol: T) -> LinearSystemSolution<T> {
+    let pivots = unsafe { aug_matrix.u_reduced_row_echelon_form_tol(rows, cols, tol) };
     let last_col = cols - 1;
-    let mut current_col = 0;
-    let mut row_offset;
-    let mut answer_vec: Vec<T> = unsafe { non_zeroed_vec(last_col) };
-    let mut basis_vecs = Vec::new();
-    let mut infinite_solutions = false;
-    let mut free_lookup = vec![false; last_col];
-    let mut free_list = Vec::with_capacity(last_col);
+    if pivots.last() == Some(&last_col) {
+        return LinearSystemSolution::None;
+    }
+    unsafe { u_solution_from_pivots(aug_matrix, &pivots, cols) }
+}
+
+// Builds the solution set of a reduced augmented matrix from its pivot columns: the
+// particular solution sets every free variable to zero, and each free column gives one
+// basis vector of the homogeneous solutions.
+pub unsafe fn u_solution_from_pivots<T: Number>(reduced: &Matrix<T>, pivots: &[usize], cols: usize) -> LinearSystemSolution<T> {
+    unsafe { u_solution_from_pivots_rhs(reduced, pivots, cols - 1, cols, cols - 1) }
+}
+
+// As u_solution_from_pivots for a reduced matrix whose first unknowns columns hold the
+// coefficients, reading the right hand side from column rhs_col. Consistency is not checked.
+pub unsafe fn u_solution_from_pivots_rhs<T: Number>(reduced: &Matrix<T>, pivots: &[usize], unknowns: usize, cols: usize, rhs_col: usize) -> LinearSystemSolution<T> {
+    let mut answer_vec = vec![T::zero(); unknowns];
+    let mut is_pivot = vec![false; unknowns];
     let mut homogeneous = true;
     unsafe {
-        'outer: for row in 0..rows {
-            row_offset = row * cols;
-            while *aug_matrix.get(row_offset + current_col) != T::one() {
-                infinite_solutions = true;
-                *free_lookup.get_unchecked_mut(current_col) = true;
-                let mut basis_vec = vec![T::zero(); last_col];
-                *basis_vec.get_unchecked_mut(current_col) = T::one();
-                basis_vecs.push(basis_vec);
-                free_list.push(current_col);
-                current_col += 1;
-                if current_col == last_col {
-                    if *aug_matrix.get(row_offset + current_col) != T::zero() {
-                        return LinearSystemSolution::None;
-                    }
-                    break 'outer;
-                }
-            }
-            let temp = *aug_matrix.get(row_offset + last_col);
-            *answer_vec.get_unchecked_mut(current_col) = temp;
-            if temp != T::zero() {
+        for (row, pivot_col) in pivots.iter().enumerate() {
+            let val = *reduced.get(row * cols + rhs_col);
+            *answer_vec.get_unchecked_mut(*pivot_col) = val;
+            *is_pivot.get_unchecked_mut(*pivot_col) = true;
+            if val != T::zero() {
                 homogeneous = false;
             }
-            current_col += 1;
         }
-        for col in current_col..last_col {
-            infinite_solutions = true;
-            *free_lookup.get_unchecked_mut(col) = true;
-            let mut basis_vec = vec![T::zero(); last_col];
-            *basis_vec.get_unchecked_mut(col) = T::one();
+        if pivots.len() == unknowns {
+            return LinearSystemSolution::Solution(answer_vec);
+        }
+        let mut basis_vecs = Vec::with_capacity(unknowns - pivots.len());
+        for free_col in (0..unknowns).filter(|col| !is_pivot[*col]) {
+            let mut basis_vec = vec![T::zero(); unknowns];
+            *basis_vec.get_unchecked_mut(free_col) = T::one();
+            for (row, pivot_col) in pivots.iter().enumerate() {
+                *basis_vec.get_unchecked_mut(*pivot_col) = -*reduced.get(row * cols + free_col);
+            }
             basis_vecs.push(basis_vec);
-            free_list.push(col);
         }
-        if !infinite_solutions {
-            LinearSystemSolution::Solution(answer_vec)
+        if homogeneous {
+            LinearSystemSolution::HomogenousBasis(basis_vecs)
         } else {
-            for (basis, col) in basis_vecs.iter_mut().zip(free_list.iter()) {
-                let mut row = 0;
-                for i in 0..last_col {
-                    if !free_lookup.get_unchecked(i) {
-                        *basis.get_unchecked_mut(i) = -*aug_matrix.get_loc(row, *col);
-                        row += 1;
-                    }
-                }
-            }
-            if homogeneous {
-                LinearSystemSolution::HomogenousBasis(basis_vecs)
-            } else {
-                LinearSystemSolution::AffineBasis(answer_vec, basis_vecs)
-            }
+            LinearSystemSolution::AffineBasis(answer_vec, basis_vecs)
         }
-
     }
-}
\ No newline at end of file
+}
+
+// An estimate of the 1-norm condition number of the coefficient block of a square system,
+// to check before trusting solve_linear_system_rref: roughly log10 of it digits are lost.
+// None when the coefficients are singular.
+pub fn estimate_condition_rref<T: RealNumber>(aug_matrix: &Matrix<T>) -> Option<T> {
+    assert!(aug_matrix.well_formed());
+    assert_eq!(aug_matrix.rows + 1, aug_matrix.cols);
+    let coefficients = Matrix::from_fn(aug_matrix.rows, aug_matrix.rows, |row, col| aug_matrix[(row, col)]);
+    coefficients.condition_number_1_estimate()
+}
@@ -1,6 +1,12 @@
 pub mod util;
+pub mod vector;
 pub mod vector_dot;
 pub mod vector_add;
 pub mod vector_sub;
 pub mod vector_neg;
-pub mod vector_scaler_mult;
\ No newline at end of file
+pub mod vector_scaler_mult;
+pub mod vector_scaler_div;
+pub mod vector_hadamard;
+pub mod vector_elementwise;
+pub mod vector_norm;
+pub mod vector_geometry;
@@ -1,9 +1,7 @@
-use crate::core::number::Number;
+use crate::core::number::Ring;
 
-pub unsafe fn non_zeroed_vec<T: Number>(size: usize) -> Vec<T> {
-    let mut v = Vec::with_capacity(size);
-    unsafe {
-        v.set_len(size);
-    }
-    v
-}
\ No newline at end of file
+// Storage for results whose every entry is written before it is read. It is zeroed, since
+// a Vec over uninitialized memory is undefined behaviour to create at all.
+pub fn zeroed_vec<T: Ring>(size: usize) -> Vec<T> {
+    vec![T::zero(); size]
+}
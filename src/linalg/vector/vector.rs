@@ -0,0 +1,209 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::{core::{approx_eq::ApproxEq, number::Ring}, linalg::matrix::matrix::Matrix};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Column,
+    Row,
+}
+
+// An owned vector that remembers whether it is a column (n x 1) or a row (1 x n) when
+// it meets a Matrix. Addition, subtraction and Hadamard operations require both vectors to
+// have the same orientation; the dot products, cross product and outer product ignore it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vector<T: Ring> {
+    pub data: Vec<T>,
+    pub orientation: Orientation,
+}
+
+impl<T: Ring> Vector<T> {
+    pub fn new(data: Vec<T>) -> Self {
+        Self::column(data)
+    }
+
+    pub fn column(data: Vec<T>) -> Self {
+        Self { data, orientation: Orientation::Column }
+    }
+
+    pub fn row(data: Vec<T>) -> Self {
+        Self { data, orientation: Orientation::Row }
+    }
+
+    pub fn zeroes(len: usize) -> Self {
+        Self::column(vec![T::zero(); len])
+    }
+
+    // The i-th standard basis vector of length len.
+    pub fn unit(len: usize, i: usize) -> Self {
+        assert!(i < len);
+        let mut v = Self::zeroes(len);
+        v.data[i] = T::one();
+        v
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn is_column(&self) -> bool {
+        self.orientation == Orientation::Column
+    }
+
+    pub fn is_row(&self) -> bool {
+        self.orientation == Orientation::Row
+    }
+
+    pub fn well_formed(&self) -> bool {
+        self.data.iter().all(|val| val.valid())
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut v = self.clone();
+        v.transpose_inplace();
+        v
+    }
+
+    pub fn transpose_inplace(&mut self) {
+        self.orientation = match self.orientation {
+            Orientation::Column => Orientation::Row,
+            Orientation::Row => Orientation::Column,
+        };
+    }
+
+    // An n x 1 matrix for a column vector and a 1 x n matrix for a row vector.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        match self.orientation {
+            Orientation::Column => Matrix::new(self.len(), 1, self.data.clone()),
+            Orientation::Row => Matrix::new(1, self.len(), self.data.clone()),
+        }
+    }
+
+    // Takes the orientation from the shape of the matrix; a 1 x 1 matrix becomes a column.
+    pub fn from_matrix(m: &Matrix<T>) -> Self {
+        assert!(m.well_formed());
+        if m.cols == 1 {
+            Self::column(m.data.clone())
+        } else {
+            assert_eq!(m.rows, 1);
+            Self::row(m.data.clone())
+        }
+    }
+}
+
+impl<T: Ring> From<Vec<T>> for Vector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self::column(data)
+    }
+}
+
+impl<T: Ring> From<&[T]> for Vector<T> {
+    fn from(data: &[T]) -> Self {
+        Self::column(data.to_vec())
+    }
+}
+
+impl<T: Ring> From<Vector<T>> for Matrix<T> {
+    fn from(v: Vector<T>) -> Self {
+        match v.orientation {
+            Orientation::Column => Matrix::new(v.data.len(), 1, v.data),
+            Orientation::Row => Matrix::new(1, v.data.len(), v.data),
+        }
+    }
+}
+
+impl<T: Ring> Index<usize> for Vector<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T: Ring> IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T: Ring> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: Ring> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<T: Ring + fmt::Display> fmt::Display for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, val) in self.data.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", val)?;
+        }
+        write!(f, "]")?;
+        if self.is_column() {
+            write!(f, "^T")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Ring + ApproxEq> ApproxEq for Vector<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.orientation == other.orientation && self.data.abs_diff_eq(&other.data, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.orientation == other.orientation && self.data.relative_eq(&other.data, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.orientation == other.orientation && self.data.ulps_eq(&other.data, epsilon, max_ulps)
+    }
+}
@@ -1,32 +1,98 @@
-use crate::{core::number::Number, linalg::vector::util::non_zeroed_vec};
+use std::ops::{Add, AddAssign};
+
+use crate::{core::number::Ring, linalg::vector::{util::zeroed_vec, vector::Vector}};
 
 #[inline(always)]
-pub fn gen_vector_add<T: Number>(a: &[T], b: &[T]) -> Vec<T> {
+pub fn gen_vector_add<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
     assert_eq!(a.len(), b.len());
     unsafe { u_gen_vector_add(a.as_ptr(), b.as_ptr(), a.len()) }
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_add<T: Number>(a: *const T, b: *const T, size: usize) -> Vec<T> {
+pub unsafe fn u_gen_vector_add<T: Ring>(a: *const T, b: *const T, size: usize) -> Vec<T> {
     unsafe {
-        let mut v = non_zeroed_vec(size);
+        let mut v = zeroed_vec(size);
         u_gen_vector_iadd(a, b, v.as_mut_ptr(), size);
         v
     }
 }
 
 #[inline(always)]
-pub fn gen_vector_iadd<T: Number>(a: &[T], b: &[T], des: &mut [T]) {
+pub fn gen_vector_iadd<T: Ring>(a: &[T], b: &[T], des: &mut [T]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(a.len(), des.len());
     unsafe { u_gen_vector_iadd(a.as_ptr(), b.as_ptr(), des.as_mut_ptr(), a.len()) }
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_iadd<T: Number>(a: *const T, b: *const T, des: *mut T, size: usize) {
+pub unsafe fn u_gen_vector_iadd<T: Ring>(a: *const T, b: *const T, des: *mut T, size: usize) {
     unsafe {
         for i in 0..size {
             *des.add(i) = *a.add(i) + *b.add(i);
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T: Ring> Vector<T> {
+    #[inline(always)]
+    pub fn add(&mut self, other: &Vector<T>) {
+        assert_eq!(self.len(), other.len());
+        assert_eq!(self.orientation, other.orientation);
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a += *b;
+        }
+    }
+}
+
+impl<T: Ring> Add for &Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.orientation, rhs.orientation);
+        Vector { data: gen_vector_add(&self.data, &rhs.data), orientation: self.orientation }
+    }
+}
+
+impl<T: Ring> Add<&Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn add(mut self, rhs: &Vector<T>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Ring> Add<Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn add(self, mut rhs: Vector<T>) -> Self::Output {
+        rhs += self;
+        rhs
+    }
+}
+
+impl<T: Ring> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl<T: Ring> AddAssign<&Vector<T>> for Vector<T> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: &Vector<T>) {
+        Vector::add(self, rhs);
+    }
+}
+
+impl<T: Ring> AddAssign for Vector<T> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        Vector::add(self, &rhs);
+    }
+}
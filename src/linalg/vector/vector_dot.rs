@@ -1,7 +1,7 @@
-use crate::core::number::Number;
+use crate::{core::{complex::ComplexNumber, number::Ring}, linalg::vector::vector::Vector};
 
 #[inline(always)]
-pub fn gen_vector_dot<T: Number>(a: &[T], b: &[T]) -> T {
+pub fn gen_vector_dot<T: Ring>(a: &[T], b: &[T]) -> T {
     assert_eq!(a.len(), b.len());
     unsafe {
         u_gen_vector_dot(a.as_ptr(), b.as_ptr(), a.len())
@@ -9,18 +9,18 @@ pub fn gen_vector_dot<T: Number>(a: &[T], b: &[T]) -> T {
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_dot<T: Number>(a: *const T, b: *const T, size: usize) -> T {
+pub unsafe fn u_gen_vector_dot<T: Ring>(a: *const T, b: *const T, size: usize) -> T {
     let mut acc= T::zero();
     unsafe {
         for i in 0..size {
-            acc = acc + (*a.add(i) * *b.add(i));
+            acc += *a.add(i) * *b.add(i);
         }
     }
     acc
 }
 
 #[inline(always)]
-pub fn gen_vector_sdot<T: Number>(a: &[T], b: &[T], a_stride: usize, b_stride: usize) -> T {
+pub fn gen_vector_sdot<T: Ring>(a: &[T], b: &[T], a_stride: usize, b_stride: usize) -> T {
     assert_eq!(a.len() / a_stride, b.len() / b_stride);
     unsafe {
         u_gen_vector_sdot(a.as_ptr(), b.as_ptr(), a_stride, b_stride, a.len() / a_stride)
@@ -28,12 +28,44 @@ pub fn gen_vector_sdot<T: Number>(a: &[T], b: &[T], a_stride: usize, b_stride: u
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_sdot<T: Number>(a: *const T, b: *const T, a_stride: usize, b_stride: usize, size: usize) -> T {
+pub unsafe fn u_gen_vector_sdot<T: Ring>(a: *const T, b: *const T, a_stride: usize, b_stride: usize, size: usize) -> T {
     let mut acc= T::zero();
     unsafe {
         for i in 0..size {
-            acc = acc + (*a.add(i*a_stride) * *b.add(i*b_stride));
+            acc += *a.add(i*a_stride) * *b.add(i*b_stride);
         }
     }
     acc
-}
\ No newline at end of file
+}
+
+// The Hermitian inner product sum(conj(a_i) * b_i), conjugate-linear in a.
+#[inline(always)]
+pub fn gen_vector_hermitian_dot<T: ComplexNumber>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len());
+    unsafe {
+        u_gen_vector_hermitian_dot(a.as_ptr(), b.as_ptr(), a.len())
+    }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_hermitian_dot<T: ComplexNumber>(a: *const T, b: *const T, size: usize) -> T {
+    let mut acc = T::zero();
+    unsafe {
+        for i in 0..size {
+            acc += (*a.add(i)).conjugate() * *b.add(i);
+        }
+    }
+    acc
+}
+
+impl<T: Ring> Vector<T> {
+    pub fn dot(&self, other: &Vector<T>) -> T {
+        gen_vector_dot(&self.data, &other.data)
+    }
+}
+
+impl<T: ComplexNumber> Vector<T> {
+    pub fn hermitian_dot(&self, other: &Vector<T>) -> T {
+        gen_vector_hermitian_dot(&self.data, &other.data)
+    }
+}
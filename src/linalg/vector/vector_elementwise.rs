@@ -0,0 +1,72 @@
+use crate::core::number::Ring;
+
+#[inline(always)]
+pub fn gen_vector_map<T: Ring, U, F: FnMut(T) -> U>(a: &[T], f: F) -> Vec<U> {
+    a.iter().copied().map(f).collect()
+}
+
+#[inline(always)]
+pub fn gen_vector_map_inplace<T: Ring, F: FnMut(T) -> T>(a: &mut [T], mut f: F) {
+    for val in a.iter_mut() {
+        *val = f(*val);
+    }
+}
+
+#[inline(always)]
+pub fn gen_vector_zip_map<T: Ring, U: Ring, R, F: FnMut(T, U) -> R>(a: &[T], b: &[U], mut f: F) -> Vec<R> {
+    assert_eq!(a.len(), b.len());
+    a.iter().zip(b.iter()).map(|(x, y)| f(*x, *y)).collect()
+}
+
+#[inline(always)]
+pub fn gen_vector_zip_apply<T: Ring, U: Ring, F: FnMut(T, U) -> T>(a: &mut [T], b: &[U], mut f: F) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x = f(*x, *y);
+    }
+}
+
+#[inline(always)]
+pub fn gen_vector_fold<T: Ring, A, F: FnMut(A, T) -> A>(a: &[T], init: A, f: F) -> A {
+    a.iter().copied().fold(init, f)
+}
+
+#[inline(always)]
+pub fn gen_vector_sum<T: Ring>(a: &[T]) -> T {
+    gen_vector_fold(a, T::zero(), |acc, val| acc + val)
+}
+
+#[inline(always)]
+pub fn gen_vector_product<T: Ring>(a: &[T]) -> T {
+    gen_vector_fold(a, T::one(), |acc, val| acc * val)
+}
+
+// Index of the first smallest element.
+pub fn gen_vector_argmin<T: Ring>(a: &[T]) -> Option<usize> {
+    let mut best = 0;
+    for i in 1..a.len() {
+        if a[i] < a[best] {
+            best = i;
+        }
+    }
+    if a.is_empty() { None } else { Some(best) }
+}
+
+// Index of the first largest element.
+pub fn gen_vector_argmax<T: Ring>(a: &[T]) -> Option<usize> {
+    let mut best = 0;
+    for i in 1..a.len() {
+        if a[i] > a[best] {
+            best = i;
+        }
+    }
+    if a.is_empty() { None } else { Some(best) }
+}
+
+pub fn gen_vector_min<T: Ring>(a: &[T]) -> Option<T> {
+    gen_vector_argmin(a).map(|i| a[i])
+}
+
+pub fn gen_vector_max<T: Ring>(a: &[T]) -> Option<T> {
+    gen_vector_argmax(a).map(|i| a[i])
+}
@@ -0,0 +1,67 @@
+use crate::{core::{complex::ComplexNumber, number::{RealNumber, Ring}}, linalg::{matrix::matrix::Matrix, vector::{vector::Vector, vector_dot::{gen_vector_dot, gen_vector_hermitian_dot}}}};
+
+#[inline(always)]
+pub fn gen_vector_cross<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
+    assert_eq!(a.len(), 3);
+    assert_eq!(b.len(), 3);
+    vec![
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+// The a.len() x b.len() matrix a * b^T.
+#[inline(always)]
+pub fn gen_vector_outer<T: Ring>(a: &[T], b: &[T]) -> Matrix<T> {
+    let mut data = Vec::with_capacity(a.len() * b.len());
+    for x in a.iter() {
+        for y in b.iter() {
+            data.push(*x * *y);
+        }
+    }
+    Matrix::new(a.len(), b.len(), data)
+}
+
+impl<T: Ring> Vector<T> {
+    pub fn cross(&self, other: &Vector<T>) -> Vector<T> {
+        Vector { data: gen_vector_cross(&self.data, &other.data), orientation: self.orientation }
+    }
+
+    pub fn outer(&self, other: &Vector<T>) -> Matrix<T> {
+        gen_vector_outer(&self.data, &other.data)
+    }
+}
+
+// Projections use the Hermitian inner product, which is the usual dot product over the reals.
+impl<T: ComplexNumber> Vector<T> {
+    // The component of self along onto.
+    pub fn projection(&self, onto: &Vector<T>) -> Vector<T> {
+        let denom = gen_vector_hermitian_dot(&onto.data, &onto.data);
+        assert!(denom != T::zero());
+        let factor = gen_vector_hermitian_dot(&onto.data, &self.data) / denom;
+        let mut v = onto * factor;
+        v.orientation = self.orientation;
+        v
+    }
+
+    // The component of self orthogonal to onto.
+    pub fn rejection(&self, onto: &Vector<T>) -> Vector<T> {
+        self - &self.projection(onto)
+    }
+}
+
+impl<T: RealNumber> Vector<T> {
+    // The angle in radians, in [0, pi].
+    pub fn angle_between(&self, other: &Vector<T>) -> T {
+        let denom = self.norm_l2() * other.norm_l2();
+        assert!(denom != T::zero());
+        let mut cos = gen_vector_dot(&self.data, &other.data) / denom;
+        if cos > T::one() {
+            cos = T::one();
+        } else if cos < -T::one() {
+            cos = -T::one();
+        }
+        cos.acos()
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{core::number::{Number, Ring}, linalg::vector::{util::zeroed_vec, vector::Vector}};
+
+#[inline(always)]
+pub fn gen_vector_hadamard_mult<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
+    assert_eq!(a.len(), b.len());
+    unsafe { u_gen_vector_hadamard_mult(a.as_ptr(), b.as_ptr(), a.len()) }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_hadamard_mult<T: Ring>(a: *const T, b: *const T, size: usize) -> Vec<T> {
+    unsafe {
+        let mut v = zeroed_vec(size);
+        u_gen_vector_ihadamard_mult(a, b, v.as_mut_ptr(), size);
+        v
+    }
+}
+
+#[inline(always)]
+pub fn gen_vector_ihadamard_mult<T: Ring>(a: &[T], b: &[T], des: &mut [T]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), des.len());
+    unsafe { u_gen_vector_ihadamard_mult(a.as_ptr(), b.as_ptr(), des.as_mut_ptr(), a.len()) }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_ihadamard_mult<T: Ring>(a: *const T, b: *const T, des: *mut T, size: usize) {
+    unsafe {
+        for i in 0..size {
+            *des.add(i) = *a.add(i) * *b.add(i);
+        }
+    }
+}
+
+#[inline(always)]
+pub fn gen_vector_hadamard_div<T: Number>(a: &[T], b: &[T]) -> Vec<T> {
+    assert_eq!(a.len(), b.len());
+    unsafe { u_gen_vector_hadamard_div(a.as_ptr(), b.as_ptr(), a.len()) }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_hadamard_div<T: Number>(a: *const T, b: *const T, size: usize) -> Vec<T> {
+    unsafe {
+        let mut v = zeroed_vec(size);
+        u_gen_vector_ihadamard_div(a, b, v.as_mut_ptr(), size);
+        v
+    }
+}
+
+#[inline(always)]
+pub fn gen_vector_ihadamard_div<T: Number>(a: &[T], b: &[T], des: &mut [T]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), des.len());
+    unsafe { u_gen_vector_ihadamard_div(a.as_ptr(), b.as_ptr(), des.as_mut_ptr(), a.len()) }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_ihadamard_div<T: Number>(a: *const T, b: *const T, des: *mut T, size: usize) {
+    unsafe {
+        for i in 0..size {
+            *des.add(i) = *a.add(i) / *b.add(i);
+        }
+    }
+}
+
+impl<T: Ring> Vector<T> {
+    pub fn hadamard_mult(&self, other: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.orientation, other.orientation);
+        Vector { data: gen_vector_hadamard_mult(&self.data, &other.data), orientation: self.orientation }
+    }
+}
+
+impl<T: Number> Vector<T> {
+    pub fn hadamard_div(&self, other: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.orientation, other.orientation);
+        Vector { data: gen_vector_hadamard_div(&self.data, &other.data), orientation: self.orientation }
+    }
+}
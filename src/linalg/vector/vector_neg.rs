@@ -1,30 +1,60 @@
-use crate::{core::number::Number, linalg::vector::util::non_zeroed_vec};
+use std::ops::Neg;
+
+use crate::{core::number::Ring, linalg::vector::{util::zeroed_vec, vector::Vector}};
 
 #[inline(always)]
-pub fn gen_vector_neg<T: Number>(a: &[T]) -> Vec<T> {
+pub fn gen_vector_neg<T: Ring>(a: &[T]) -> Vec<T> {
     unsafe { u_gen_vector_neg(a.as_ptr(), a.len()) }
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_neg<T: Number>(a: *const T, size: usize) -> Vec<T> {
+pub unsafe fn u_gen_vector_neg<T: Ring>(a: *const T, size: usize) -> Vec<T> {
     unsafe {
-        let mut v = non_zeroed_vec(size);
+        let mut v = zeroed_vec(size);
         u_gen_vector_ineg(a, v.as_mut_ptr(), size);
         v
     }
 }
 
 #[inline(always)]
-pub fn gen_vector_ineg<T: Number>(a: &[T], des: &mut [T]) {
+pub fn gen_vector_ineg<T: Ring>(a: &[T], des: &mut [T]) {
     assert_eq!(a.len(), des.len());
     unsafe { u_gen_vector_ineg(a.as_ptr(), des.as_mut_ptr(), a.len()) }
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_ineg<T: Number>(a: *const T, des: *mut T, size: usize) {
+pub unsafe fn u_gen_vector_ineg<T: Ring>(a: *const T, des: *mut T, size: usize) {
     unsafe {
         for i in 0..size {
             *des.add(i) = -*a.add(i);
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T: Ring> Vector<T> {
+    #[inline(always)]
+    pub fn negate(&mut self) {
+        for val in self.data.iter_mut() {
+            *val = -*val;
+        }
+    }
+}
+
+impl<T: Ring> Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Vector { data: gen_vector_neg(&self.data), orientation: self.orientation }
+    }
+}
+
+impl<T: Ring> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn neg(mut self) -> Self::Output {
+        self.negate();
+        self
+    }
+}
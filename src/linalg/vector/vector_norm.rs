@@ -0,0 +1,105 @@
+use crate::{core::{complex::Complex, number::RealNumber}, linalg::vector::vector::Vector};
+
+#[inline(always)]
+pub fn gen_vector_norm_l1<T: RealNumber>(a: &[T]) -> T {
+    a.iter().fold(T::zero(), |acc, val| acc + val.abs())
+}
+
+// Scales by the largest magnitude first so the squares cannot overflow or underflow.
+#[inline(always)]
+pub fn gen_vector_norm_l2<T: RealNumber>(a: &[T]) -> T {
+    let scale = gen_vector_norm_linf(a);
+    if scale == T::zero() {
+        return T::zero();
+    }
+    let sum = a.iter().fold(T::zero(), |acc, val| {
+        let x = *val / scale;
+        acc + x * x
+    });
+    scale * sum.sqrt()
+}
+
+#[inline(always)]
+pub fn gen_vector_norm_linf<T: RealNumber>(a: &[T]) -> T {
+    a.iter().fold(T::zero(), |acc, val| {
+        let x = val.abs();
+        if x > acc { x } else { acc }
+    })
+}
+
+#[inline(always)]
+pub fn gen_vector_norm_lp<T: RealNumber>(a: &[T], p: T) -> T {
+    assert!(p >= T::one());
+    let scale = gen_vector_norm_linf(a);
+    if scale == T::zero() {
+        return T::zero();
+    }
+    let sum = a.iter().fold(T::zero(), |acc, val| acc + (val.abs() / scale).power(p));
+    scale * sum.power(T::one() / p)
+}
+
+impl<T: RealNumber> Vector<T> {
+    pub fn norm_l1(&self) -> T {
+        gen_vector_norm_l1(&self.data)
+    }
+
+    pub fn norm_l2(&self) -> T {
+        gen_vector_norm_l2(&self.data)
+    }
+
+    pub fn norm_linf(&self) -> T {
+        gen_vector_norm_linf(&self.data)
+    }
+
+    pub fn norm_lp(&self, p: T) -> T {
+        gen_vector_norm_lp(&self.data, p)
+    }
+
+    // The unit vector in the same direction under the l2 norm.
+    pub fn normalize(&self) -> Vector<T> {
+        let mut v = self.clone();
+        v.normalize_inplace();
+        v
+    }
+
+    pub fn normalize_inplace(&mut self) {
+        let norm = self.norm_l2();
+        assert!(norm != T::zero());
+        self.divide(norm);
+    }
+}
+
+// Norms of complex vectors are the real norms of the vector of moduli.
+impl<T: RealNumber> Vector<Complex<T>> {
+    pub fn moduli(&self) -> Vec<T> {
+        self.data.iter().map(|z| z.abs()).collect()
+    }
+
+    pub fn norm_l1(&self) -> T {
+        gen_vector_norm_l1(&self.moduli())
+    }
+
+    pub fn norm_l2(&self) -> T {
+        gen_vector_norm_l2(&self.moduli())
+    }
+
+    pub fn norm_linf(&self) -> T {
+        gen_vector_norm_linf(&self.moduli())
+    }
+
+    pub fn norm_lp(&self, p: T) -> T {
+        gen_vector_norm_lp(&self.moduli(), p)
+    }
+
+    pub fn normalize(&self) -> Vector<Complex<T>> {
+        let mut v = self.clone();
+        v.normalize_inplace();
+        v
+    }
+
+    pub fn normalize_inplace(&mut self) {
+        let norm = self.norm_l2();
+        assert!(norm != T::zero());
+        self.divide(Complex { re: norm, im: T::zero() });
+    }
+}
@@ -0,0 +1,67 @@
+use std::ops::{Div, DivAssign};
+
+use crate::{core::number::Number, linalg::vector::{util::zeroed_vec, vector::Vector}};
+
+#[inline(always)]
+pub fn gen_vector_scaler_div<T: Number>(v: &[T], a: T) -> Vec<T> {
+    unsafe { u_gen_vector_scaler_div(v.as_ptr(), a, v.len()) }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_scaler_div<T: Number>(v: *const T, a: T, size: usize) -> Vec<T> {
+    unsafe {
+        let mut des = zeroed_vec(size);
+        u_gen_vector_scaler_idiv(v, a, des.as_mut_ptr(), size);
+        des
+    }
+}
+
+#[inline(always)]
+pub fn gen_vector_scaler_idiv<T: Number>(v: &[T], a: T, des: &mut [T]) {
+    assert_eq!(v.len(), des.len());
+    unsafe { u_gen_vector_scaler_idiv(v.as_ptr(), a, des.as_mut_ptr(), v.len()) }
+}
+
+#[inline(always)]
+pub unsafe fn u_gen_vector_scaler_idiv<T: Number>(v: *const T, a: T, des: *mut T, size: usize) {
+    unsafe {
+        for i in 0..size {
+            *des.add(i) = *v.add(i) / a;
+        }
+    }
+}
+
+impl<T: Number> Vector<T> {
+    #[inline(always)]
+    pub fn divide(&mut self, a: T) {
+        for val in self.data.iter_mut() {
+            *val /= a;
+        }
+    }
+}
+
+impl<T: Number> Div<T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn div(self, rhs: T) -> Self::Output {
+        Vector { data: gen_vector_scaler_div(&self.data, rhs), orientation: self.orientation }
+    }
+}
+
+impl<T: Number> Div<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn div(mut self, rhs: T) -> Self::Output {
+        self.divide(rhs);
+        self
+    }
+}
+
+impl<T: Number> DivAssign<T> for Vector<T> {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: T) {
+        self.divide(rhs);
+    }
+}
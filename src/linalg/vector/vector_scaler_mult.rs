@@ -1,30 +1,132 @@
-use crate::{core::number::Number, linalg::vector::util::non_zeroed_vec};
+use std::ops::{Mul, MulAssign};
+
+use crate::{core::{complex::Complex, modp::ModP, number::{RealNumber, Ring}, rational::Rational}, linalg::vector::{util::zeroed_vec, vector::Vector}};
 
 #[inline(always)]
-pub fn gen_vector_scaler_mult<T: Number>(a: T, v: &[T]) -> Vec<T> {
+pub fn gen_vector_scaler_mult<T: Ring>(a: T, v: &[T]) -> Vec<T> {
     unsafe { u_gen_vector_scaler_mult(a, v.as_ptr(), v.len()) }
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_scaler_mult<T: Number>(a: T, v: *const T, size: usize) -> Vec<T> {
+pub unsafe fn u_gen_vector_scaler_mult<T: Ring>(a: T, v: *const T, size: usize) -> Vec<T> {
     unsafe {
-        let mut des = non_zeroed_vec(size);
+        let mut des = zeroed_vec(size);
         u_gen_vector_scaler_imult(a, v, des.as_mut_ptr(), size);
         des
     }
 }
 
 #[inline(always)]
-pub fn gen_vector_scaler_imult<T: Number>(a: T, v: &[T], des: &mut [T]) {
+pub fn gen_vector_scaler_imult<T: Ring>(a: T, v: &[T], des: &mut [T]) {
     assert_eq!(v.len(), des.len());
     unsafe { u_gen_vector_scaler_imult(a, v.as_ptr(), des.as_mut_ptr(), v.len()) }
 }
 
 #[inline(always)]
-pub unsafe fn u_gen_vector_scaler_imult<T: Number>(a: T, v: *const T, des: *mut T, size: usize) {
+pub unsafe fn u_gen_vector_scaler_imult<T: Ring>(a: T, v: *const T, des: *mut T, size: usize) {
     unsafe {
         for i in 0..size {
             *des.add(i) = a * *v.add(i);
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T: Ring> Vector<T> {
+    #[inline(always)]
+    pub fn scale(&mut self, a: T) {
+        for val in self.data.iter_mut() {
+            *val = a * *val;
+        }
+    }
+}
+
+impl<T: Ring> Mul<T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector { data: gen_vector_scaler_mult(rhs, &self.data), orientation: self.orientation }
+    }
+}
+
+impl<T: Ring> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline(always)]
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.scale(rhs);
+        self
+    }
+}
+
+impl<T: Ring> MulAssign<T> for Vector<T> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: T) {
+        self.scale(rhs);
+    }
+}
+
+macro_rules! impl_scaler_left_mult {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<&Vector<$t>> for $t {
+                type Output = Vector<$t>;
+
+                #[inline(always)]
+                fn mul(self, rhs: &Vector<$t>) -> Self::Output {
+                    Vector { data: gen_vector_scaler_mult(self, &rhs.data), orientation: rhs.orientation }
+                }
+            }
+
+            impl Mul<Vector<$t>> for $t {
+                type Output = Vector<$t>;
+
+                #[inline(always)]
+                fn mul(self, mut rhs: Vector<$t>) -> Self::Output {
+                    rhs.scale(self);
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_scaler_left_mult!(f32, f64, i8, i16, i32, i64, i128, isize, Rational);
+
+impl<T: RealNumber> Mul<&Vector<Complex<T>>> for Complex<T> {
+    type Output = Vector<Complex<T>>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Vector<Complex<T>>) -> Self::Output {
+        Vector { data: gen_vector_scaler_mult(self, &rhs.data), orientation: rhs.orientation }
+    }
+}
+
+impl<T: RealNumber> Mul<Vector<Complex<T>>> for Complex<T> {
+    type Output = Vector<Complex<T>>;
+
+    #[inline(always)]
+    fn mul(self, mut rhs: Vector<Complex<T>>) -> Self::Output {
+        rhs.scale(self);
+        rhs
+    }
+}
+
+impl<const P: u64> Mul<&Vector<ModP<P>>> for ModP<P> {
+    type Output = Vector<ModP<P>>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &Vector<ModP<P>>) -> Self::Output {
+        Vector { data: gen_vector_scaler_mult(self, &rhs.data), orientation: rhs.orientation }
+    }
+}
+
+impl<const P: u64> Mul<Vector<ModP<P>>> for ModP<P> {
+    type Output = Vector<ModP<P>>;
+
+    #[inline(always)]
+    fn mul(self, mut rhs: Vector<ModP<P>>) -> Self::Output {
+        rhs.scale(self);
+        rhs
+    }
+}
@@ -0,0 +1,91 @@
+use luna::{core::{approx_eq::{ApproxEq, Tolerance}, complex::Complex, modp::ModP, rational::Rational}, linalg::matrix::matrix::Matrix};
+
+// The float n representable values above x, for x positive.
+fn ulps_above(x: f64, n: u64) -> f64 {
+    f64::from_bits(x.to_bits() + n)
+}
+
+#[test]
+fn ulp_distance() {
+    for x in [1.0, 1e-300, 123456.789, f64::MIN_POSITIVE, f64::MAX / 2.0] {
+        let near = ulps_above(x, 4);
+        let far = ulps_above(x, 5);
+        assert!(x.ulps_eq(&near, 0.0, 4));
+        assert!(near.ulps_eq(&x, 0.0, 4));
+        assert!(!x.ulps_eq(&far, 0.0, 4));
+        assert!((-x).ulps_eq(&-near, 0.0, 4));
+        assert!(!(-x).ulps_eq(&-far, 0.0, 4));
+    }
+    // The ULP count is independent of magnitude, unlike an absolute tolerance.
+    assert!(1e20.ulps_eq(&ulps_above(1e20, 1), 0.0, 1));
+    assert!(!1e20.abs_diff_eq(&ulps_above(1e20, 1), 1.0));
+    let third = 1.0f32 / 3.0;
+    assert!(third.ulps_eq(&f32::from_bits(third.to_bits() + 2), 0.0, 2));
+    assert!(!third.ulps_eq(&f32::from_bits(third.to_bits() + 3), 0.0, 2));
+}
+
+#[test]
+fn ulps_across_zero() {
+    let tiny = f64::from_bits(1);
+    // Values of opposite sign are never close in ULPs, so only epsilon can join them.
+    assert!(!tiny.ulps_eq(&-tiny, 0.0, 10));
+    assert!(tiny.ulps_eq(&-tiny, 1e-300, 10));
+    assert!(0.0.ulps_eq(&-0.0, 0.0, 0));
+    assert!(0.0.ulps_eq(&tiny, 0.0, 1));
+}
+
+#[test]
+fn special_values() {
+    assert!(!f64::NAN.ulps_eq(&f64::NAN, 1.0, 100));
+    assert!(!f64::NAN.approx_eq(&f64::NAN));
+    assert!(!f64::NAN.abs_diff_eq(&0.0, f64::INFINITY));
+    assert!(f64::INFINITY.ulps_eq(&f64::INFINITY, 0.0, 0));
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY));
+    assert!(!f64::INFINITY.ulps_eq(&f64::MAX, 0.0, 4));
+    assert!(!f64::INFINITY.approx_eq(&f64::MAX));
+    assert!(!f64::INFINITY.ulps_eq(&f64::NEG_INFINITY, 0.0, u32::MAX));
+}
+
+#[test]
+fn relative_and_absolute() {
+    assert!((0.1 + 0.2).approx_eq(&0.3));
+    assert!(!0.3.abs_diff_eq(&(0.1 + 0.2), 0.0));
+    assert!(1e6.relative_eq(&(1e6 + 1e-4), 0.0, 1e-9));
+    assert!(!1e-6.relative_eq(&(1e-6 + 1e-4), 0.0, 1e-9));
+    assert!(1e-6.relative_eq(&(1e-6 + 1e-4), 1e-3, 1e-9));
+    let x = 2.0;
+    assert!(x.approx_eq_with(&2.1, Tolerance::Absolute(0.2)));
+    assert!(!x.approx_eq_with(&2.1, Tolerance::Relative { epsilon: 0.0, max_relative: 0.01 }));
+    assert!(x.approx_eq_with(&ulps_above(2.0, 3), Tolerance::Ulps { epsilon: 0.0, max_ulps: 3 }));
+    assert_eq!(f64::default_max_ulps(), 4);
+}
+
+#[test]
+fn complex_and_exact_types() {
+    let a = Complex { re: 1.0, im: -2.0 };
+    let b = Complex { re: ulps_above(1.0, 2), im: -2.0 };
+    assert!(a.ulps_eq(&b, 0.0, 2));
+    assert!(!a.ulps_eq(&b, 0.0, 1));
+    assert!(a.approx_eq(&b));
+    assert!(!a.approx_eq(&Complex { re: 1.0, im: 2.0 }));
+
+    let half = Rational { pos: true, num: 1, den: 2 };
+    assert!(half.approx_eq(&half));
+    assert!(!half.approx_eq(&Rational { pos: true, num: 500000001, den: 1000000000 }));
+    assert!(ModP::<7>::new(10).approx_eq(&ModP::new(3)));
+    assert!(!ModP::<7>::new(10).ulps_eq(&ModP::new(4), (), 100));
+}
+
+#[test]
+fn sequences_and_matrices() {
+    let a = vec![1.0, 2.0, 3.0];
+    assert!(a.approx_eq(&vec![1.0, 2.0, 3.0 + 1e-16]));
+    assert!(!a.approx_eq(&vec![1.0, 2.0]));
+    assert!(!a.approx_eq(&vec![1.0, 2.0, 3.1]));
+    let m = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let n = m.map(|val| val * (1.0 + 1e-16));
+    assert!(m.approx_eq(&n));
+    assert!(m.ulps_eq(&n, 0.0, 4));
+    // Same data in a different shape is not equal.
+    assert!(!m.approx_eq(&Matrix::new(3, 2, m.data.clone())));
+}
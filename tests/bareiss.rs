@@ -0,0 +1,59 @@
+use luna::{core::rational::Rational, linalg::{matrix::matrix::Matrix, solvers::bareiss_solver::solve_linear_system_bareiss}};
+
+#[test]
+fn determinants_match_known_values() {
+    let a = Matrix::from_rows(&[vec![2i64, -3, 1], vec![2, 0, -1], vec![1, 4, 5]]);
+    assert_eq!(a.determinant_bareiss(), 49);
+    // Needs a row swap on the first pivot.
+    let swapped = Matrix::from_rows(&[vec![0i64, 1, 2], vec![3, 4, 5], vec![6, 7, 9]]);
+    assert_eq!(swapped.determinant_bareiss(), -3);
+    let singular = Matrix::from_rows(&[vec![1i64, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    assert_eq!(singular.determinant_bareiss(), 0);
+    // The 4x4 Hilbert matrix scaled by 420 has determinant 420^4 / 6048000.
+    let hilbert = Matrix::from_fn(4, 4, |row, col| 420 / (row + col + 1) as i64);
+    assert_eq!(hilbert.determinant_bareiss(), 5145);
+    assert_eq!(Matrix::<i64>::identity(5).determinant_bareiss(), 1);
+}
+
+#[test]
+fn determinant_of_rational_matrix() {
+    let r = |num: i64, den: u64| Rational { pos: num >= 0, num: num.unsigned_abs(), den };
+    let a = Matrix::from_rows(&[vec![r(1, 2), r(1, 3)], vec![r(1, 4), r(-1, 5)]]);
+    assert!(a.determinant_bareiss() == r(-1, 10) - r(1, 12));
+}
+
+#[test]
+fn ranks_of_square_and_rectangular_matrices() {
+    assert_eq!(Matrix::from_rows(&[vec![1i64, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).rank_bareiss(), 2);
+    assert_eq!(Matrix::from_rows(&[vec![1i64, 2, 3, 4], vec![2, 4, 6, 8]]).rank_bareiss(), 1);
+    assert_eq!(Matrix::from_rows(&[vec![0i64, 0], vec![0, 0], vec![0, 0]]).rank_bareiss(), 0);
+    assert_eq!(Matrix::from_rows(&[vec![0i64, 1], vec![1, 0], vec![1, 1]]).rank_bareiss(), 2);
+    assert_eq!(Matrix::from_rows(&[vec![1i64, 0, 2, 0, 1], vec![0, 0, 1, 3, 0], vec![1, 0, 3, 3, 1]]).rank_bareiss(), 2);
+}
+
+#[test]
+fn solves_integer_system_over_common_denominator() {
+    // x + 2y = 1, 3x + 4y = 1 has x = -1, y = 1.
+    let (numerators, den) = solve_linear_system_bareiss(&Matrix::from_rows(&[vec![1i64, 2, 1], vec![3, 4, 1]])).unwrap();
+    assert_eq!(numerators.len(), 2);
+    assert_eq!((-numerators[0], numerators[1]), (den, den));
+    // 2x + y = 1, x + 3y = 2 has x = 1 / 5, y = 3 / 5.
+    let (numerators, den) = solve_linear_system_bareiss(&Matrix::from_rows(&[vec![2i64, 1, 1], vec![1, 3, 2]])).unwrap();
+    assert_eq!((numerators[0] * 5, numerators[1] * 5), (den, 3 * den));
+}
+
+#[test]
+fn inconsistent_and_underdetermined_systems() {
+    assert!(solve_linear_system_bareiss(&Matrix::from_rows(&[vec![1i64, 1, 1], vec![2, 2, 3]])).is_none());
+    // x + y + z = 6 with x - z = 0: the free variable is set to zero.
+    let (numerators, den) = solve_linear_system_bareiss(&Matrix::from_rows(&[vec![1i64, 1, 1, 6], vec![1, 0, -1, 0]])).unwrap();
+    let x: Vec<i64> = numerators.iter().map(|val| val / den).collect();
+    assert!(numerators.iter().all(|val| val % den == 0));
+    assert_eq!((x[0] + x[1] + x[2], x[0] - x[2], x[2]), (6, 0, 0));
+}
+
+#[test]
+#[should_panic]
+fn empty_augmented_matrix_is_rejected() {
+    solve_linear_system_bareiss(&Matrix::<i64>::zeroes(2, 0));
+}
@@ -0,0 +1,161 @@
+use luna::linalg::{bit_matrix::{bit_matrix::BitMatrix, bit_matrix_mult::gen_bit_matrix_mult}, solvers::bit_matrix_solver::solve_linear_system_bit_matrix};
+
+struct Bits(u64);
+
+impl Bits {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn random_rows(bits: &mut Bits, rows: usize, cols: usize) -> Vec<Vec<bool>> {
+    (0..rows).map(|_| (0..cols).map(|_| bits.next() & 1 == 1).collect()).collect()
+}
+
+fn to_bit_matrix(rows: &[Vec<bool>], cols: usize) -> BitMatrix {
+    let mut m = BitMatrix::zeroes(rows.len(), cols);
+    for (row, vals) in rows.iter().enumerate() {
+        for (col, val) in vals.iter().enumerate() {
+            m.set(row, col, *val);
+        }
+    }
+    m
+}
+
+fn to_rows(m: &BitMatrix) -> Vec<Vec<bool>> {
+    (0..m.rows).map(|row| (0..m.cols).map(|col| m.get(row, col)).collect()).collect()
+}
+
+// Textbook Gauss-Jordan elimination, one row at a time.
+fn naive_rref(rows: &mut [Vec<bool>], cols: usize) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    for col in 0..cols {
+        let start = pivots.len();
+        let Some(found) = (start..rows.len()).find(|row| rows[*row][col]) else {
+            continue;
+        };
+        rows.swap(start, found);
+        for row in 0..rows.len() {
+            if row != start && rows[row][col] {
+                let pivot_row = rows[start].clone();
+                rows[row].iter_mut().zip(pivot_row.iter()).for_each(|(val, pivot)| *val ^= *pivot);
+            }
+        }
+        pivots.push(col);
+    }
+    pivots
+}
+
+// Widths around word boundaries and around multiples of the 8 column table block.
+const SHAPES: [(usize, usize); 12] = [(1, 1), (5, 7), (9, 9), (20, 17), (40, 63), (64, 64), (70, 65), (30, 100), (100, 30), (130, 129), (16, 200), (200, 72)];
+
+#[test]
+fn m4ri_matches_naive_elimination() {
+    let mut bits = Bits(0x243f6a8885a308d3);
+    for (rows, cols) in SHAPES {
+        for trial in 0..4 {
+            let mut expected = random_rows(&mut bits, rows, cols);
+            // Repeat rows and zero columns so that some blocks have fewer than 8 pivots.
+            if trial % 2 == 1 && rows > 2 {
+                expected[rows - 1] = expected[0].clone();
+                expected.iter_mut().for_each(|row| row[cols / 2] = false);
+            }
+            let mut m = to_bit_matrix(&expected, cols);
+            let expected_pivots = naive_rref(&mut expected, cols);
+            assert_eq!(m.reduced_row_echelon_form(), expected_pivots, "{rows}x{cols}");
+            assert_eq!(to_rows(&m), expected, "{rows}x{cols}");
+            assert_eq!(to_bit_matrix(&to_rows(&m), cols).rank(), expected_pivots.len());
+        }
+    }
+}
+
+#[test]
+fn null_space_is_annihilated() {
+    let mut bits = Bits(0x13198a2e03707344);
+    for (rows, cols) in SHAPES {
+        let m = to_bit_matrix(&random_rows(&mut bits, rows, cols), cols);
+        let basis = m.null_space();
+        assert_eq!(basis.rows, cols - m.rank());
+        let product = gen_bit_matrix_mult(&m, &transpose(&basis));
+        assert!(to_rows(&product).iter().flatten().all(|val| !val));
+        assert_eq!(basis.rank(), basis.rows);
+    }
+}
+
+#[test]
+fn multiplication_matches_naive_product() {
+    let mut bits = Bits(0xa4093822299f31d0);
+    for (n, k, m) in [(3, 5, 7), (64, 64, 64), (65, 130, 9), (17, 200, 66)] {
+        let a = random_rows(&mut bits, n, k);
+        let b = random_rows(&mut bits, k, m);
+        let product = gen_bit_matrix_mult(&to_bit_matrix(&a, k), &to_bit_matrix(&b, m));
+        for (row, vals) in to_rows(&product).iter().enumerate() {
+            for (col, val) in vals.iter().enumerate() {
+                assert_eq!(*val, (0..k).fold(false, |acc, i| acc ^ (a[row][i] & b[i][col])));
+            }
+        }
+    }
+}
+
+fn transpose(m: &BitMatrix) -> BitMatrix {
+    let mut t = BitMatrix::zeroes(m.cols, m.rows);
+    for row in 0..m.rows {
+        for col in 0..m.cols {
+            t.set(col, row, m.get(row, col));
+        }
+    }
+    t
+}
+
+fn satisfies(a: &[Vec<bool>], b: &[bool], x: &[bool]) -> bool {
+    a.iter().zip(b.iter()).all(|(row, rhs)| row.iter().zip(x.iter()).fold(false, |acc, (a, x)| acc ^ (a & x)) == *rhs)
+}
+
+fn augmented(a: &[Vec<bool>], b: &[bool]) -> BitMatrix {
+    let rows: Vec<Vec<bool>> = a.iter().zip(b.iter()).map(|(row, rhs)| row.iter().copied().chain([*rhs]).collect()).collect();
+    to_bit_matrix(&rows, a[0].len() + 1)
+}
+
+#[test]
+fn solver_on_consistent_systems() {
+    let mut bits = Bits(0x082efa98ec4e6c89);
+    for (rows, cols) in [(9, 9), (64, 64), (70, 65), (30, 100), (130, 129)] {
+        let a = random_rows(&mut bits, rows, cols);
+        let x0: Vec<bool> = (0..cols).map(|_| bits.next() & 1 == 1).collect();
+        let b: Vec<bool> = a.iter().map(|row| row.iter().zip(x0.iter()).fold(false, |acc, (a, x)| acc ^ (a & x))).collect();
+        let x = solve_linear_system_bit_matrix(&augmented(&a, &b)).expect("b is in the column space");
+        assert!(satisfies(&a, &b, &x), "{rows}x{cols}");
+    }
+}
+
+#[test]
+fn solver_on_underdetermined_system() {
+    // x0 + x1 + x2 = 1 and x1 + x3 = 0: the free variables x2 and x3 are set to zero.
+    let a = vec![vec![true, true, true, false], vec![false, true, false, true]];
+    let b = vec![true, false];
+    let x = solve_linear_system_bit_matrix(&augmented(&a, &b)).unwrap();
+    assert_eq!(x, vec![true, false, false, false]);
+}
+
+#[test]
+fn solver_on_inconsistent_systems() {
+    let a = vec![vec![true, true], vec![true, true]];
+    assert!(solve_linear_system_bit_matrix(&augmented(&a, &[true, false])).is_none());
+    // A wide system whose last row sums to a contradiction.
+    let mut bits = Bits(0x452821e638d01377);
+    let mut a = random_rows(&mut bits, 20, 90);
+    let mut b: Vec<bool> = (0..20).map(|_| bits.next() & 1 == 1).collect();
+    let sum = a.iter().fold(vec![false; 90], |acc, row| acc.iter().zip(row.iter()).map(|(x, y)| x ^ y).collect());
+    a.push(sum);
+    b.push(!b.iter().fold(false, |acc, val| acc ^ val));
+    assert!(solve_linear_system_bit_matrix(&augmented(&a, &b)).is_none());
+}
+
+#[test]
+#[should_panic]
+fn empty_augmented_matrix_is_rejected() {
+    solve_linear_system_bit_matrix(&BitMatrix::zeroes(3, 0));
+}
@@ -0,0 +1,114 @@
+use luna::{core::rational::Rational, linalg::{matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult}, polynomial::polynomial::Polynomial}};
+
+fn frac(num: i64, den: u64) -> Rational {
+    let mut val = Rational { pos: num >= 0, num: num.unsigned_abs(), den };
+    val.simplify();
+    val
+}
+
+fn r(num: i64) -> Rational {
+    frac(num, 1)
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col]))
+}
+
+fn rational_poly(highest: &[i64]) -> Polynomial<Rational> {
+    Polynomial::from_highest(&highest.iter().map(|c| r(*c)).collect::<Vec<_>>())
+}
+
+// Q * J * Q^-1 for a fixed invertible Q, which hides the block structure of J.
+fn conjugate(j: &Matrix<Rational>) -> Matrix<Rational> {
+    let size = j.rows;
+    let q = Matrix::from_fn(size, size, |row, col| if row <= col { r(1 + (col - row) as i64 % 2) } else if row == col + 1 { r(-1) } else { r(0) });
+    let q_inverse = q.lu().inverse().unwrap();
+    gen_matrix_mult(&gen_matrix_mult(&q, j), &q_inverse)
+}
+
+fn jordan_matrix(blocks: &[(Rational, usize)]) -> Matrix<Rational> {
+    let size = blocks.iter().map(|(_, length)| length).sum();
+    let mut j = Matrix::zeroes(size, size);
+    let mut offset = 0;
+    for (lambda, length) in blocks {
+        for i in 0..*length {
+            j[(offset + i, offset + i)] = *lambda;
+            if i + 1 < *length {
+                j[(offset + i, offset + i + 1)] = r(1);
+            }
+        }
+        offset += length;
+    }
+    j
+}
+
+fn check_rational_canonical_form(a: &Matrix<Rational>) -> Vec<Polynomial<Rational>> {
+    let form = a.rational_canonical_form();
+    assert!(form.p.lu().inverse().is_some());
+    assert_eq!(gen_matrix_mult(&form.p, &form.f).data, gen_matrix_mult(a, &form.p).data);
+    let factors = form.invariant_factors;
+    for pair in factors.windows(2) {
+        assert!(pair[1].div_rem(&pair[0]).1.is_zero(), "{} does not divide {}", pair[0], pair[1]);
+    }
+    assert!(factors.iter().all(|factor| factor.is_monic()));
+    let product = factors.iter().fold(Polynomial::one(), |acc, factor| &acc * factor);
+    assert_eq!(product, a.characteristic_polynomial());
+    assert_eq!(factors.last().unwrap(), &a.minimal_polynomial());
+    factors
+}
+
+#[test]
+fn rational_canonical_form_similarity_and_divisibility() {
+    let a = conjugate(&rational(&[&[2, 0, 0], &[0, 2, 0], &[0, 0, 3]]));
+    assert_eq!(check_rational_canonical_form(&a), vec![rational_poly(&[1, -2]), rational_poly(&[1, -5, 6])]);
+
+    // Blocks J_2(1), J_1(1) and J_1(1) give (x - 1) | (x - 1) | (x - 1)^2.
+    let a = conjugate(&jordan_matrix(&[(r(1), 2), (r(1), 1), (r(1), 1)]));
+    assert_eq!(check_rational_canonical_form(&a), vec![rational_poly(&[1, -1]), rational_poly(&[1, -1]), rational_poly(&[1, -2, 1])]);
+
+    // Cyclic, so the characteristic polynomial is the only invariant factor.
+    let a = rational(&[&[1, 2, 0, -1], &[0, 1, 3, 2], &[4, 0, -2, 1], &[1, 1, 1, 0]]);
+    assert_eq!(check_rational_canonical_form(&a).len(), 1);
+
+    let scalar = Matrix::from_fn(3, 3, |row, col| if row == col { frac(-2, 3) } else { r(0) });
+    assert_eq!(check_rational_canonical_form(&scalar).len(), 3);
+}
+
+#[test]
+fn jordan_form_with_several_eigenvalues() {
+    let blocks = vec![(r(-1), 3), (frac(1, 2), 1), (r(2), 2), (r(2), 1)];
+    let a = conjugate(&jordan_matrix(&blocks));
+    let form = a.jordan_form().expect("the characteristic polynomial splits");
+    assert!(form.p.lu().inverse().is_some());
+    assert_eq!(gen_matrix_mult(&form.p, &form.j).data, gen_matrix_mult(&a, &form.p).data);
+    assert_eq!(form.blocks, blocks);
+    assert_eq!(form.j.data, jordan_matrix(&blocks).data);
+}
+
+#[test]
+fn jordan_form_of_nilpotent_and_diagonalizable_matrices() {
+    // Roots at zero are factored out before the rational root search.
+    let blocks = vec![(r(0), 2), (r(0), 2), (r(3), 1)];
+    let a = conjugate(&jordan_matrix(&blocks));
+    let form = a.jordan_form().unwrap();
+    assert_eq!(form.blocks, blocks);
+    assert_eq!(gen_matrix_mult(&form.p, &form.j).data, gen_matrix_mult(&a, &form.p).data);
+
+    let a = conjugate(&Matrix::from_diagonal(&[frac(-7, 3), frac(5, 4), r(97)]));
+    let form = a.jordan_form().unwrap();
+    assert_eq!(form.blocks, vec![(frac(-7, 3), 1), (frac(5, 4), 1), (r(97), 1)]);
+    assert_eq!(gen_matrix_mult(&form.p, &form.j).data, gen_matrix_mult(&a, &form.p).data);
+}
+
+#[test]
+fn jordan_form_needs_a_splitting_characteristic_polynomial() {
+    // x^2 - 2 has the irrational roots +-sqrt(2).
+    let sqrt2 = rational_poly(&[1, 0, -2]).companion_matrix();
+    assert!(sqrt2.jordan_form().is_none());
+    // x^2 + 1 has no real roots, and (x - 1) * (x^2 - 2) splits only partly.
+    assert!(rational(&[&[0, -1], &[1, 0]]).jordan_form().is_none());
+    let partly = (&rational_poly(&[1, -1]) * &rational_poly(&[1, 0, -2])).companion_matrix();
+    assert!(partly.jordan_form().is_none());
+    // The rational canonical form exists regardless.
+    assert_eq!(check_rational_canonical_form(&sqrt2), vec![rational_poly(&[1, 0, -2])]);
+}
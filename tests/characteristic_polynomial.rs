@@ -0,0 +1,99 @@
+use luna::{core::{modp::ModP, rational::Rational}, linalg::{matrix::matrix::Matrix, polynomial::polynomial::Polynomial}};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col]))
+}
+
+fn rational_poly(highest: &[i64]) -> Polynomial<Rational> {
+    Polynomial::from_highest(&highest.iter().map(|c| r(*c)).collect::<Vec<_>>())
+}
+
+fn integer(rows: &[&[i64]]) -> Matrix<i64> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| rows[row][col])
+}
+
+#[test]
+fn berkowitz_matches_determinant_of_x_minus_a() {
+    let a = integer(&[&[2, -1, 0, 3], &[1, 4, -2, 0], &[0, 5, 1, -1], &[-3, 0, 2, 2]]);
+    let p = a.characteristic_polynomial();
+    assert_eq!(p.degree(), Some(4));
+    assert!(p.is_monic());
+    // The trace and determinant appear as -c_3 and c_0.
+    assert_eq!(p.coefficient(3), -9);
+    assert_eq!(p.coefficient(0), a.determinant_bareiss());
+    for x in -4..=4 {
+        let shifted = Matrix::from_fn(4, 4, |row, col| if row == col { x } else { 0 } - a[(row, col)]);
+        assert_eq!(p.eval(x), shifted.determinant_bareiss(), "x = {x}");
+    }
+    assert!(a.satisfies_cayley_hamilton());
+}
+
+#[test]
+fn berkowitz_needs_no_division() {
+    // The zero diagonal would stop elimination without pivoting; Berkowitz only multiplies.
+    let a = integer(&[&[0, 1, 0], &[0, 0, 1], &[6, -11, 6]]);
+    assert_eq!(a.characteristic_polynomial(), Polynomial::from_highest(&[1, -6, 11, -6]));
+    let mod7 = Matrix::from_fn(3, 3, |row, col| ModP::<7>::from_i64(a[(row, col)]));
+    assert_eq!(mod7.characteristic_polynomial(), Polynomial::from_highest(&[1, -6, 11, -6].map(ModP::<7>::from_i64)));
+    assert_eq!(Matrix::<i64>::zeroes(0, 0).characteristic_polynomial(), Polynomial::one());
+}
+
+#[test]
+fn characteristic_polynomial_of_companion_matrix() {
+    let p = rational_poly(&[1, -2, 0, 5, -7]);
+    assert_eq!(p.companion_matrix().characteristic_polynomial(), p);
+    // A non-monic polynomial comes back divided by its leading coefficient.
+    let q = rational_poly(&[3, 0, -6]);
+    assert_eq!(q.companion_matrix().characteristic_polynomial(), q.monic());
+}
+
+#[test]
+fn cayley_hamilton_with_tolerance() {
+    let a = Matrix::from_rows(&[vec![0.1, 0.7, 0.3], vec![0.3, 0.1, 0.9], vec![0.7, 0.3, 0.1]]);
+    assert!(a.satisfies_cayley_hamilton_tol(1e-12));
+}
+
+#[test]
+fn minimal_polynomial_of_diagonal_and_jordan_matrices() {
+    let diagonal = rational(&[&[2, 0, 0], &[0, 2, 0], &[0, 0, 3]]);
+    assert_eq!(diagonal.minimal_polynomial(), rational_poly(&[1, -5, 6]));
+    assert_eq!(diagonal.characteristic_polynomial(), rational_poly(&[1, -7, 16, -12]));
+
+    // A 2 x 2 Jordan block for 2 needs the square of x - 2.
+    let jordan = rational(&[&[2, 1, 0], &[0, 2, 0], &[0, 0, 3]]);
+    assert_eq!(jordan.minimal_polynomial(), rational_poly(&[1, -7, 16, -12]));
+
+    assert_eq!(Matrix::<Rational>::identity(4).minimal_polynomial(), rational_poly(&[1, -1]));
+    assert_eq!(Matrix::<Rational>::zeroes(3, 3).minimal_polynomial(), rational_poly(&[1, 0]));
+    assert_eq!(Matrix::<Rational>::zeroes(0, 0).minimal_polynomial(), Polynomial::one());
+}
+
+#[test]
+fn minimal_polynomial_divides_characteristic_polynomial() {
+    let cases = [
+        // Blocks J_2(1), J_1(1) and J_1(-1).
+        (rational(&[&[1, 2, 0, 0], &[0, 1, 0, 0], &[0, 0, 1, 0], &[0, 0, 0, -1]]), rational_poly(&[1, -1, -1, 1])),
+        // 3 I + N with N of rank one and N^2 = 0.
+        (rational(&[&[3, 1, 1], &[0, 3, 0], &[0, 0, 3]]), rational_poly(&[1, -6, 9])),
+        // Two nilpotent 2 x 2 blocks.
+        (rational(&[&[0, 1, 0, 0], &[0, 0, 0, 0], &[0, 0, 0, 1], &[0, 0, 0, 0]]), rational_poly(&[1, 0, 0])),
+    ];
+    for (a, expected) in cases {
+        let minimal = a.minimal_polynomial();
+        assert_eq!(minimal, expected);
+        assert!(minimal.eval_matrix(&a).data.iter().all(|val| *val == r(0)));
+        let (_, remainder) = a.characteristic_polynomial().div_rem(&minimal);
+        assert!(remainder.is_zero());
+    }
+}
+
+#[test]
+fn minimal_polynomial_over_finite_field() {
+    // Over F_2 the matrix is idempotent, so x^2 - x annihilates it.
+    let a = Matrix::from_fn(3, 3, |row, col| ModP::<2>::new([[1, 1, 0], [0, 0, 0], [0, 0, 1]][row][col]));
+    assert_eq!(a.minimal_polynomial(), Polynomial::from_highest(&[1, 1, 0].map(ModP::<2>::new)));
+}
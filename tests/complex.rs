@@ -0,0 +1,26 @@
+use luna::core::complex::Complex;
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex { re, im }
+}
+
+#[test]
+fn add_assign_matches_add() {
+    let (a, b) = (c(1.5, -2.0), c(-0.25, 3.0));
+    let mut sum = a;
+    sum += b;
+    assert!(sum == a + b);
+    assert!(sum == c(1.25, 1.0));
+}
+
+#[test]
+fn ge_and_le_agree_with_partial_cmp() {
+    let values = [c(0.0, 0.0), c(1.0, -1.0), c(1.0, 2.0), c(-3.0, 5.0)];
+    for a in values {
+        for b in values {
+            assert_eq!(a >= b, a.partial_cmp(&b).is_some_and(|ord| ord.is_ge()));
+            assert_eq!(a <= b, a.partial_cmp(&b).is_some_and(|ord| ord.is_le()));
+        }
+    }
+    assert!(c(2.0, 1.0) >= c(2.0, 1.0) && c(2.0, 1.0) <= c(2.0, 1.0));
+}
@@ -0,0 +1,71 @@
+use luna::linalg::matrix::matrix::Matrix;
+
+#[test]
+fn rows_cols_and_closures_agree() {
+    let by_rows = Matrix::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]]);
+    let by_cols = Matrix::from_cols(&[vec![1, 4], vec![2, 5], vec![3, 6]]);
+    let by_fn = Matrix::from_fn(2, 3, |row, col| (row * 3 + col + 1) as i64);
+    assert_eq!(by_rows.shape(), (2, 3));
+    assert_eq!(by_cols.shape(), (2, 3));
+    assert_eq!(by_rows.data, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(by_cols.data, by_rows.data);
+    assert_eq!(by_fn.data, by_rows.data);
+    assert_eq!(Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).data, by_rows.data);
+}
+
+#[test]
+fn diagonal_filled_and_identity() {
+    let d = Matrix::from_diagonal(&[3, -1, 7]);
+    assert_eq!(d.data, vec![3, 0, 0, 0, -1, 0, 0, 0, 7]);
+    assert_eq!(Matrix::filled(2, 2, 9).data, vec![9; 4]);
+    assert_eq!(Matrix::<i64>::identity(3).data, Matrix::from_diagonal(&[1, 1, 1]).data);
+    assert_eq!(Matrix::<i64>::zeroes(2, 3).data, vec![0; 6]);
+}
+
+#[test]
+fn empty_shapes() {
+    assert_eq!(Matrix::<i64>::from_rows(&[]).shape(), (0, 0));
+    assert_eq!(Matrix::<i64>::from_cols(&[]).shape(), (0, 0));
+    assert_eq!(Matrix::<i64>::from_rows(&[vec![], vec![]]).shape(), (2, 0));
+    assert_eq!(Matrix::<i64>::from_cols(&[vec![], vec![], vec![]]).shape(), (0, 3));
+    assert_eq!(Matrix::<i64>::from_diagonal(&[]).shape(), (0, 0));
+    assert_eq!(Matrix::<i64>::block(&[]).shape(), (0, 0));
+}
+
+#[test]
+fn block_assembly() {
+    let a = Matrix::from_rows(&[vec![1, 2], vec![3, 4]]);
+    let b = Matrix::from_rows(&[vec![5], vec![6]]);
+    let c = Matrix::from_rows(&[vec![7, 8]]);
+    let d = Matrix::from_rows(&[vec![9]]);
+    let m = Matrix::block(&[&[&a, &b], &[&c, &d]]);
+    assert_eq!(m.shape(), (3, 3));
+    assert_eq!(m.data, vec![1, 2, 5, 3, 4, 6, 7, 8, 9]);
+}
+
+#[test]
+fn stacking() {
+    let a = Matrix::from_rows(&[vec![1, 2], vec![3, 4]]);
+    let b = Matrix::from_rows(&[vec![5], vec![6]]);
+    let h = Matrix::hstack(&[&a, &b]);
+    assert_eq!(h.shape(), (2, 3));
+    assert_eq!(h.data, vec![1, 2, 5, 3, 4, 6]);
+    let c = Matrix::from_rows(&[vec![7, 8]]);
+    let v = Matrix::vstack(&[&a, &c, &a]);
+    assert_eq!(v.shape(), (5, 2));
+    assert_eq!(v.data, vec![1, 2, 3, 4, 7, 8, 1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn ragged_rows_are_rejected() {
+    Matrix::from_rows(&[vec![1, 2], vec![3]]);
+}
+
+#[test]
+#[should_panic]
+fn mismatched_block_heights_are_rejected() {
+    let a = Matrix::from_rows(&[vec![1, 2], vec![3, 4]]);
+    let c = Matrix::from_rows(&[vec![7, 8]]);
+    Matrix::hstack(&[&a, &c]);
+}
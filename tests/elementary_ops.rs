@@ -0,0 +1,94 @@
+use luna::{core::rational::Rational, linalg::matrix::{matrix::Matrix, matrix_elementary::{ColOp, RowOp}, matrix_mult::gen_matrix_mult}};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn sample() -> Matrix<i64> {
+    Matrix::from_rows(&[vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]])
+}
+
+#[test]
+fn row_operations() {
+    let mut a = sample();
+    a.swap_rows(0, 2);
+    assert_eq!(a.data, vec![9, 10, 11, 12, 5, 6, 7, 8, 1, 2, 3, 4]);
+    a.scale_row(1, -2);
+    assert_eq!(a.row(1), &[-10, -12, -14, -16]);
+    a.add_row_multiple(0, 2, 3);
+    assert_eq!(a.row(0), &[12, 16, 20, 24]);
+    assert_eq!(a.row(2), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn column_operations() {
+    let mut a = sample();
+    a.swap_cols(0, 3);
+    assert_eq!(a.row(0), &[4, 2, 3, 1]);
+    a.scale_col(1, 10);
+    assert_eq!(a.data, vec![4, 20, 3, 1, 8, 60, 7, 5, 12, 100, 11, 9]);
+    a.add_col_multiple(2, 3, -1);
+    assert_eq!(a.data, vec![4, 20, 2, 1, 8, 60, 2, 5, 12, 100, 2, 9]);
+}
+
+#[test]
+fn elementary_matrices_match_operations() {
+    let a = sample();
+    for op in [RowOp::Swap(0, 2), RowOp::Scale(1, 7), RowOp::AddMultiple(2, 0, -3)] {
+        let mut applied = a.clone();
+        op.apply(&mut applied);
+        assert_eq!(gen_matrix_mult(&op.elementary_matrix(3), &a).data, applied.data);
+    }
+    for op in [ColOp::Swap(1, 3), ColOp::Scale(0, -2), ColOp::AddMultiple(3, 1, 5)] {
+        let mut applied = a.clone();
+        op.apply(&mut applied);
+        assert_eq!(gen_matrix_mult(&a, &op.elementary_matrix(4)).data, applied.data);
+    }
+}
+
+#[test]
+fn sequence_matrices_compose_in_order() {
+    let a = sample();
+    let row_ops = [RowOp::Swap(0, 1), RowOp::AddMultiple(2, 0, 2), RowOp::Scale(0, -1), RowOp::Swap(1, 2)];
+    let mut applied = a.clone();
+    row_ops.iter().for_each(|op| op.apply(&mut applied));
+    assert_eq!(gen_matrix_mult(&RowOp::sequence_matrix(&row_ops, 3), &a).data, applied.data);
+
+    let col_ops = [ColOp::Swap(0, 3), ColOp::AddMultiple(1, 2, -1), ColOp::Scale(2, 4)];
+    let mut applied = a.clone();
+    col_ops.iter().for_each(|op| op.apply(&mut applied));
+    assert_eq!(gen_matrix_mult(&a, &ColOp::sequence_matrix(&col_ops, 4)).data, applied.data);
+
+    // Swaps alone give a permutation matrix.
+    let p = RowOp::<i64>::sequence_matrix(&[RowOp::Swap(0, 1), RowOp::Swap(1, 2)], 3);
+    assert_eq!(p.data, vec![0, 1, 0, 0, 0, 1, 1, 0, 0]);
+}
+
+#[test]
+fn inverse_operations_undo() {
+    let a = Matrix::from_fn(3, 3, |row, col| r((row * 3 + col) as i64 - 4));
+    let ops = [RowOp::Swap(0, 2), RowOp::Scale(1, Rational { pos: false, num: 2, den: 3 }), RowOp::AddMultiple(0, 1, r(5))];
+    for op in ops {
+        let mut m = a.clone();
+        op.apply(&mut m);
+        op.inverse().apply(&mut m);
+        assert_eq!(m.data, a.data);
+    }
+    let mut m = a.clone();
+    let op = ColOp::AddMultiple(2, 0, r(-3));
+    op.apply(&mut m);
+    op.inverse().apply(&mut m);
+    assert_eq!(m.data, a.data);
+}
+
+#[test]
+#[should_panic]
+fn adding_row_to_itself_panics() {
+    sample().add_row_multiple(1, 1, 2);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range_column_panics() {
+    sample().swap_cols(0, 4);
+}
@@ -0,0 +1,92 @@
+use luna::{core::{complex::Complex, rational::Rational}, linalg::{matrix::{matrix::Matrix, matrix_hadamard::{gen_matrix_hadamard_div, gen_matrix_hadamard_mult, gen_matrix_ihadamard_mult}}, vector::{vector_elementwise::{gen_vector_argmax, gen_vector_argmin, gen_vector_fold, gen_vector_map, gen_vector_map_inplace, gen_vector_max, gen_vector_min, gen_vector_product, gen_vector_sum, gen_vector_zip_apply, gen_vector_zip_map}, vector_hadamard::{gen_vector_hadamard_div, gen_vector_hadamard_mult}}}};
+
+fn r(num: i64, den: u64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den }
+}
+
+fn sample() -> Matrix<i64> {
+    Matrix::from_rows(&[vec![3, -1, 4], vec![1, -5, 9], vec![2, 6, -5]])
+}
+
+#[test]
+fn map_and_zip() {
+    let a = sample();
+    assert_eq!(a.map(|val| val * val).data, vec![9, 1, 16, 1, 25, 81, 4, 36, 25]);
+    let mut b = a.clone();
+    b.map_inplace(|val| val + 1);
+    assert_eq!(b.data, a.data.iter().map(|val| val + 1).collect::<Vec<_>>());
+    assert_eq!(a.zip_map(&b, |x, y| y - x).data, vec![1; 9]);
+    b.zip_apply(&a, |x, y| x * y);
+    assert_eq!(b[(1, 1)], 20);
+}
+
+#[test]
+fn type_changing_map() {
+    let a = Matrix::from_rows(&[vec![1.0, -2.0], vec![0.5, 3.0]]);
+    let c = a.map(|re| Complex { re, im: -re });
+    assert_eq!(c.shape(), (2, 2));
+    assert!(c.iter_indexed().all(|(loc, z)| z.re == a[loc] && z.im == -a[loc]));
+    assert_eq!(gen_vector_map(&[1i64, 2, 3], |val| val as f64 / 2.0), vec![0.5, 1.0, 1.5]);
+}
+
+#[test]
+fn folds_and_extremes() {
+    let a = sample();
+    assert_eq!(a.sum(), 14);
+    assert_eq!(a.product(), -32400);
+    assert_eq!(a.fold(0, |acc, val| acc.max(val.abs())), 9);
+    // Ties go to the first occurrence.
+    assert_eq!(a.min(), Some(-5));
+    assert_eq!(a.argmin(), Some((1, 1)));
+    assert_eq!(a.max(), Some(9));
+    assert_eq!(a.argmax(), Some((1, 2)));
+    let empty = Matrix::<i64>::zeroes(0, 4);
+    assert_eq!(empty.argmin(), None);
+    assert_eq!(empty.sum(), 0);
+    assert_eq!(empty.product(), 1);
+}
+
+#[test]
+fn vector_combinators() {
+    let v = [2i64, 7, -3, 7, -3];
+    assert_eq!(gen_vector_sum(&v), 10);
+    assert_eq!(gen_vector_product(&v), 2 * 7 * -3 * 7 * -3);
+    assert_eq!(gen_vector_fold(&v, 0, |acc, val| acc + (val > 0) as i64), 3);
+    assert_eq!((gen_vector_argmin(&v), gen_vector_min(&v)), (Some(2), Some(-3)));
+    assert_eq!((gen_vector_argmax(&v), gen_vector_max(&v)), (Some(1), Some(7)));
+    assert_eq!(gen_vector_argmax::<i64>(&[]), None);
+    let mut w = v;
+    gen_vector_map_inplace(&mut w, |val| -val);
+    assert_eq!(gen_vector_zip_map(&v, &w, |a, b| a + b), vec![0; 5]);
+    gen_vector_zip_apply(&mut w, &v, |a, b| a * b);
+    assert_eq!(w, [-4, -49, -9, -49, -9]);
+}
+
+#[test]
+fn hadamard_products() {
+    let a = Matrix::from_fn(2, 3, |row, col| r((row * 3 + col) as i64 - 2, 1));
+    let b = Matrix::from_fn(2, 3, |row, col| r(1 + (row + col) as i64, 3));
+    let prod = gen_matrix_hadamard_mult(&a, &b);
+    assert!(prod.iter_indexed().all(|(loc, val)| *val == a[loc] * b[loc]));
+    let quot = gen_matrix_hadamard_div(&prod, &b);
+    assert_eq!(quot.data, a.data);
+    let mut c = a.clone();
+    c.hadamard_mult(&b);
+    assert_eq!(c.data, prod.data);
+    c.hadamard_div(&b);
+    assert_eq!(c.data, a.data);
+    let mut des = Matrix::zeroes(2, 3);
+    gen_matrix_ihadamard_mult(&a, &b, &mut des);
+    assert_eq!(des.data, prod.data);
+
+    let x = [r(1, 2), r(-3, 1), r(5, 7)];
+    let y = [r(4, 1), r(2, 3), r(-7, 5)];
+    assert_eq!(gen_vector_hadamard_mult(&x, &y), vec![r(2, 1), r(-2, 1), r(-1, 1)]);
+    assert_eq!(gen_vector_hadamard_div(&gen_vector_hadamard_mult(&x, &y), &y), x.to_vec());
+}
+
+#[test]
+#[should_panic]
+fn mismatched_shapes_are_rejected() {
+    sample().zip_map(&Matrix::<i64>::zeroes(3, 2), |a, b| a + b);
+}
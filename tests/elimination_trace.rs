@@ -0,0 +1,54 @@
+use luna::{core::{modp::ModP, rational::Rational}, linalg::matrix::{matrix::Matrix, matrix_elementary::RowOp, matrix_mult::gen_matrix_mult}};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col]))
+}
+
+fn check_trace<T: luna::core::number::Number>(a: &Matrix<T>) {
+    let trace = a.reduced_row_echelon_form_traced();
+    let mut rref = a.clone();
+    rref.reduced_row_echelon_form();
+    assert_eq!(trace.result().data, rref.data);
+    assert_eq!(trace.replay_forward().data, rref.data);
+    assert_eq!(trace.replay_backward().data, a.data);
+    for step in 0..trace.len() {
+        let mut state = trace.state(step).clone();
+        trace.step_forward(&mut state, step);
+        assert_eq!(state.data, trace.state(step + 1).data);
+        trace.step_backward(&mut state, step);
+        assert_eq!(state.data, trace.state(step).data);
+    }
+    // The product of the elementary matrices carries A to its reduced form.
+    let e = RowOp::sequence_matrix(&trace.ops(), a.rows);
+    assert_eq!(gen_matrix_mult(&e, a).data, rref.data);
+}
+
+#[test]
+fn replaying_trace_reproduces_rref() {
+    check_trace(&rational(&[&[0, 2, 4], &[1, 1, 1], &[3, -1, 2]]));
+    // Rank deficient and rectangular, with a zero column skipped.
+    check_trace(&rational(&[&[0, 1, 2, 0, 3], &[0, 2, 4, 1, 1], &[0, 3, 6, 1, 4]]));
+    check_trace(&rational(&[&[1, 2], &[2, 4], &[3, 7], &[0, 0]]));
+    check_trace(&Matrix::from_fn(4, 4, |row, col| ModP::<7>::new((row * 3 + col * col) as u64)));
+}
+
+#[test]
+fn trace_of_reduced_matrix_is_empty() {
+    let a = rational(&[&[1, 0, 5], &[0, 1, -2]]);
+    let trace = a.reduced_row_echelon_form_traced();
+    assert!(trace.is_empty());
+    assert_eq!(trace.result().data, a.data);
+    let zero = Matrix::<Rational>::zeroes(2, 3);
+    assert!(zero.reduced_row_echelon_form_traced().is_empty());
+}
+
+#[test]
+fn trace_records_expected_operations() {
+    let trace = rational(&[&[0, 2], &[1, 1]]).reduced_row_echelon_form_traced();
+    let half = Rational { pos: true, num: 1, den: 2 };
+    assert_eq!(trace.ops(), vec![RowOp::Swap(0, 1), RowOp::Scale(1, half), RowOp::AddMultiple(0, 1, r(-1))]);
+}
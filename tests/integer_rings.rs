@@ -0,0 +1,39 @@
+use luna::{core::number::{IntegralDomain, Ring}, linalg::{matrix::{matrix::Matrix, matrix_add::gen_matrix_add, matrix_mult::gen_matrix_mult, matrix_neg::gen_matrix_neg, matrix_sub::gen_matrix_sub, matrix_transpose::gen_matrix_transpose}, vector::{vector_add::gen_vector_add, vector_dot::gen_vector_dot}}};
+
+#[test]
+fn integer_ring_constants_and_exact_division() {
+    assert_eq!((i8::zero(), i8::one()), (0, 1));
+    assert_eq!((i128::zero(), i128::one()), (0, 1));
+    assert!(i64::MIN.valid() && i64::MAX.valid());
+    assert_eq!(42i64.exact_div(-7), -6);
+    assert_eq!((-1_000_000_007i128 * 3).exact_div(3), -1_000_000_007);
+    assert_eq!(6.0f64.exact_div(4.0), 1.5);
+}
+
+#[test]
+fn integer_matrix_arithmetic() {
+    let a = Matrix::from_rows(&[vec![1i32, -2, 3], vec![4, 5, -6]]);
+    let b = Matrix::from_rows(&[vec![7i32, 8, 9], vec![-1, 0, 2]]);
+    assert_eq!(gen_matrix_add(&a, &b).data, vec![8, 6, 12, 3, 5, -4]);
+    assert_eq!(gen_matrix_sub(&a, &b).data, vec![-6, -10, -6, 5, 5, -8]);
+    assert_eq!(gen_matrix_neg(&a).data, vec![-1, 2, -3, -4, -5, 6]);
+    let at = gen_matrix_transpose(&a);
+    assert_eq!((at.rows, at.cols), (3, 2));
+    assert_eq!(at.data, vec![1, 4, -2, 5, 3, -6]);
+    let product = gen_matrix_mult(&a, &gen_matrix_transpose(&b));
+    assert_eq!(product.data, vec![18, 5, 14, -16]);
+    assert_eq!(product.trace(), 2);
+}
+
+#[test]
+fn integer_vector_operations() {
+    assert_eq!(gen_vector_add(&[1i16, -2, 3], &[4, 5, -6]), vec![5, 3, -3]);
+    assert_eq!(gen_vector_dot(&[1i64, -2, 3], &[4, 5, -6]), -24);
+}
+
+#[test]
+fn bareiss_over_each_integer_width() {
+    let rows = [[3, 1, 4], [1, 5, 9], [2, 6, 5]];
+    assert_eq!(Matrix::from_fn(3, 3, |row, col| rows[row][col] as i16).determinant_bareiss(), -90);
+    assert_eq!(Matrix::from_fn(3, 3, |row, col| rows[row][col] as isize).determinant_bareiss(), -90);
+}
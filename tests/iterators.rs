@@ -0,0 +1,80 @@
+use luna::linalg::matrix::matrix::Matrix;
+
+fn sample() -> Matrix<i64> {
+    Matrix::from_rows(&[vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]])
+}
+
+#[test]
+fn rows_and_columns() {
+    let a = sample();
+    let rows: Vec<&[i64]> = a.rows_iter().collect();
+    assert_eq!(rows, vec![&[1, 2, 3, 4][..], &[5, 6, 7, 8], &[9, 10, 11, 12]]);
+    assert_eq!(a.rows_iter().len(), 3);
+    assert_eq!(a.rows_iter().next_back().unwrap(), &[9, 10, 11, 12]);
+
+    let cols: Vec<Vec<i64>> = a.cols_iter().map(|col| col.to_vec()).collect();
+    assert_eq!(cols, vec![vec![1, 5, 9], vec![2, 6, 10], vec![3, 7, 11], vec![4, 8, 12]]);
+    let last = a.cols_iter().next_back().unwrap();
+    assert_eq!(last.len(), 3);
+    assert_eq!(last[1], 8);
+    assert_eq!(last.iter().rev().copied().collect::<Vec<_>>(), vec![12, 8, 4]);
+}
+
+#[test]
+fn indexed_and_diagonal() {
+    let a = sample();
+    for ((row, col), val) in a.iter_indexed() {
+        assert_eq!(*val, a[(row, col)]);
+    }
+    assert_eq!(a.iter_indexed().len(), 12);
+    assert_eq!(a.iter_indexed().nth(6), Some(((1, 2), &7)));
+    assert_eq!(a.diagonal_iter().copied().collect::<Vec<_>>(), vec![1, 6, 11]);
+    let tall = Matrix::from_fn(4, 2, |row, col| (row * 2 + col) as i64);
+    assert_eq!(tall.diagonal_iter().copied().collect::<Vec<_>>(), vec![0, 3]);
+    assert_eq!(Matrix::<i64>::zeroes(0, 3).diagonal_iter().len(), 0);
+}
+
+#[test]
+fn mutable_iterators() {
+    let mut a = sample();
+    for row in a.rows_iter_mut() {
+        row[0] = 0;
+    }
+    for mut col in a.cols_iter_mut().skip(3) {
+        for val in col.iter_mut() {
+            *val = -*val;
+        }
+        col[0] = 100;
+    }
+    for val in a.diagonal_iter_mut() {
+        *val *= 10;
+    }
+    for ((row, col), val) in a.iter_indexed_mut() {
+        if row == 2 && col == 1 {
+            *val = 42;
+        }
+    }
+    assert_eq!(a.data, vec![0, 2, 3, 100, 0, 60, 7, -8, 0, 42, 110, -12]);
+}
+
+#[test]
+fn into_iterator() {
+    let mut a = sample();
+    assert_eq!((&a).into_iter().sum::<i64>(), 78);
+    for val in &mut a {
+        *val += 1;
+    }
+    assert_eq!(a.into_iter().collect::<Vec<_>>(), (2..14).collect::<Vec<i64>>());
+}
+
+#[test]
+fn empty_matrices() {
+    let a = Matrix::<i64>::zeroes(3, 0);
+    assert_eq!(a.rows_iter().len(), 3);
+    assert!(a.rows_iter().all(|row| row.is_empty()));
+    assert_eq!(a.cols_iter().len(), 0);
+    assert_eq!(a.iter_indexed().len(), 0);
+    let b = Matrix::<i64>::zeroes(0, 3);
+    assert_eq!(b.rows_iter().len(), 0);
+    assert!(b.cols_iter().all(|col| col.is_empty()));
+}
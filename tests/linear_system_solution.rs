@@ -0,0 +1,100 @@
+use luna::{core::rational::Rational, linalg::{matrix::matrix::Matrix, solvers::{linear_system_solution::LinearSystemSolution, row_reduction_solver::solve_linear_system_basis_rref}}};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn rv(vals: &[i64]) -> Vec<Rational> {
+    vals.iter().map(|val| r(*val)).collect()
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col]))
+}
+
+// x0 + 2 x1 + 3 x3 = 5 and x2 - x3 = 2.
+fn affine_system() -> Matrix<Rational> {
+    rational(&[&[1, 2, 0, 3, 5], &[0, 0, 1, -1, 2]])
+}
+
+#[test]
+fn accessors() {
+    let solution = solve_linear_system_basis_rref(&affine_system());
+    assert!(solution.is_consistent());
+    assert!(!solution.is_unique());
+    assert_eq!(solution.dimension(), Some(2));
+    assert_eq!(solution.particular(), Some(rv(&[5, 0, 2, 0])));
+    assert_eq!(solution.basis(), &[rv(&[-2, 1, 0, 0]), rv(&[-3, 0, 1, 1])]);
+    assert_eq!(solution.sample(&[r(1), r(-1)]), Some(rv(&[6, 1, 1, -1])));
+
+    let unique = LinearSystemSolution::Solution(rv(&[1, 2]));
+    assert!(unique.is_unique());
+    assert_eq!(unique.dimension(), Some(0));
+    assert!(unique.basis().is_empty());
+    assert_eq!(unique.sample(&[]), Some(rv(&[1, 2])));
+
+    let homogeneous = LinearSystemSolution::HomogenousBasis(vec![rv(&[-1, 1])]);
+    assert_eq!(homogeneous.particular(), Some(rv(&[0, 0])));
+    assert_eq!(homogeneous.sample(&[r(3)]), Some(rv(&[-3, 3])));
+
+    let none = LinearSystemSolution::<Rational>::None;
+    assert!(!none.is_consistent());
+    assert_eq!(none.dimension(), None);
+    assert_eq!(none.particular(), None);
+    assert_eq!(none.sample(&[]), None);
+}
+
+#[test]
+fn display() {
+    let solution = LinearSystemSolution::AffineBasis(rv(&[1, 0, 2]), vec![rv(&[-2, 1, 0]), rv(&[-3, 0, 1])]);
+    assert_eq!(solution.to_string(), "x = [1, 0, 2] + t₁·[-2, 1, 0] + t₂·[-3, 0, 1]");
+    assert_eq!(LinearSystemSolution::HomogenousBasis(vec![rv(&[1, 1])]).to_string(), "x = t₁·[1, 1]");
+    assert_eq!(LinearSystemSolution::Solution(rv(&[4, -5])).to_string(), "x = [4, -5]");
+    assert_eq!(LinearSystemSolution::<Rational>::None.to_string(), "no solution");
+    let many = LinearSystemSolution::HomogenousBasis(vec![rv(&[0]); 12]);
+    assert!(many.to_string().ends_with("t₁₂·[0]"));
+}
+
+#[test]
+fn clone_compare_and_debug() {
+    let solution = solve_linear_system_basis_rref(&affine_system());
+    let copy = solution.clone();
+    assert_eq!(copy, solution);
+    assert_ne!(copy, LinearSystemSolution::None);
+    assert!(format!("{:?}", LinearSystemSolution::Solution(vec![1.5, 2.0])).contains("Solution([1.5, 2.0])"));
+}
+
+#[test]
+fn verify_complete_solution_sets() {
+    let aug = affine_system();
+    assert!(solve_linear_system_basis_rref(&aug).verify(&aug));
+    // A different but equally valid description also verifies.
+    let reparameterized = LinearSystemSolution::AffineBasis(rv(&[6, 1, 1, -1]), vec![rv(&[-5, 1, 1, 1]), rv(&[-3, 0, 1, 1])]);
+    assert!(reparameterized.verify(&aug));
+    let inconsistent = rational(&[&[1, 1, 1], &[1, 1, 2]]);
+    assert!(LinearSystemSolution::None.verify(&inconsistent));
+    assert!(!LinearSystemSolution::None.verify(&aug));
+}
+
+#[test]
+fn verify_rejects_wrong_solution_sets() {
+    let aug = affine_system();
+    // The particular solution is off.
+    assert!(!LinearSystemSolution::AffineBasis(rv(&[5, 0, 3, 0]), vec![rv(&[-2, 1, 0, 0]), rv(&[-3, 0, 1, 1])]).verify(&aug));
+    // A basis vector outside the null space.
+    assert!(!LinearSystemSolution::AffineBasis(rv(&[5, 0, 2, 0]), vec![rv(&[-2, 1, 0, 0]), rv(&[0, 0, 1, 1])]).verify(&aug));
+    // Too few basis vectors, and dependent ones.
+    assert!(!LinearSystemSolution::AffineBasis(rv(&[5, 0, 2, 0]), vec![rv(&[-2, 1, 0, 0])]).verify(&aug));
+    assert!(!LinearSystemSolution::AffineBasis(rv(&[5, 0, 2, 0]), vec![rv(&[-2, 1, 0, 0]), rv(&[4, -2, 0, 0])]).verify(&aug));
+    // Wrong length, and a unique answer for a system with free variables.
+    assert!(!LinearSystemSolution::Solution(rv(&[5, 2])).verify(&aug));
+    assert!(!LinearSystemSolution::Solution(rv(&[5, 0, 2, 0])).verify(&aug));
+}
+
+#[test]
+fn verify_with_tolerance() {
+    let aug = Matrix::from_rows(&[vec![0.1, 0.2, 0.3], vec![0.4, 0.5, 0.6]]);
+    let nearly = LinearSystemSolution::Solution(vec![-1.0 + 1e-14, 2.0]);
+    assert!(!nearly.verify(&aug));
+    assert!(nearly.verify_tol(&aug, 1e-12));
+}
@@ -0,0 +1,81 @@
+use luna::{core::rational::Rational, linalg::{matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult}, solvers::{linear_system_solution::LinearSystemSolution, matrix_equation_solver::{solve_matrix, solve_matrix_right, solve_matrix_right_tol, solve_matrix_tol}}}};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col]))
+}
+
+#[test]
+fn inverse_from_identity_right_hand_side() {
+    let a = rational(&[&[2, 1, 1], &[1, 3, 2], &[1, 0, 0]]);
+    let solution = solve_matrix(&a, &Matrix::identity(3));
+    assert!(solution.all_consistent());
+    let x = solution.solution().unwrap();
+    assert_eq!(gen_matrix_mult(&a, &x).data, Matrix::<Rational>::identity(3).data);
+    assert_eq!(gen_matrix_mult(&x, &a).data, Matrix::<Rational>::identity(3).data);
+}
+
+#[test]
+fn several_right_hand_sides() {
+    let a = rational(&[&[1, 2], &[3, 4], &[5, 6]]);
+    let x = rational(&[&[1, 0, -2, 7], &[-1, 3, 5, 0]]);
+    let b = gen_matrix_mult(&a, &x);
+    let solution = solve_matrix(&a, &b);
+    assert_eq!(solution.columns.len(), 4);
+    assert!(!solution.transposed);
+    assert_eq!(solution.solution().unwrap().data, x.data);
+}
+
+#[test]
+fn consistency_per_column() {
+    // Rank two: the third row is the sum of the first two.
+    let a = rational(&[&[1, 0, 1], &[0, 1, 1], &[1, 1, 2]]);
+    let b = rational(&[&[1, 1, 0], &[2, 1, 0], &[3, 5, 0]]);
+    let solution = solve_matrix(&a, &b);
+    assert_eq!(solution.consistent_columns(), vec![0, 2]);
+    assert_eq!(solution.inconsistent_columns(), vec![1]);
+    assert!(!solution.all_consistent());
+    assert!(solution.particular().is_none());
+    assert!(solution.solution().is_none());
+    assert_eq!(solution.columns[1], LinearSystemSolution::None);
+    assert_eq!(solution.columns[0], LinearSystemSolution::AffineBasis(vec![r(1), r(2), r(0)], vec![vec![r(-1), r(-1), r(1)]]));
+    assert_eq!(solution.columns[2], LinearSystemSolution::HomogenousBasis(vec![vec![r(-1), r(-1), r(1)]]));
+
+    // Dropping the inconsistent column leaves a particular solution for the rest.
+    let consistent = Matrix::from_cols(&[vec![r(1), r(2), r(3)], vec![r(0), r(0), r(0)]]);
+    let particular = solve_matrix(&a, &consistent).particular().unwrap();
+    assert_eq!(gen_matrix_mult(&a, &particular).data, consistent.data);
+}
+
+#[test]
+fn right_division() {
+    let a = rational(&[&[1, 2, 0], &[0, 1, 1], &[1, 0, 3]]);
+    let x = rational(&[&[2, -1, 0], &[1, 1, 1]]);
+    let b = gen_matrix_mult(&x, &a);
+    let solution = solve_matrix_right(&a, &b);
+    assert!(solution.transposed);
+    assert_eq!(solution.columns.len(), 2);
+    let found = solution.solution().unwrap();
+    assert_eq!(found.shape(), (2, 3));
+    assert_eq!(found.data, x.data);
+    // An inconsistent row of B is reported by its index.
+    let singular = rational(&[&[1, 2], &[2, 4]]);
+    let rows = rational(&[&[3, 6], &[1, 0]]);
+    assert_eq!(solve_matrix_right(&singular, &rows).inconsistent_columns(), vec![1]);
+}
+
+#[test]
+fn float_tolerance() {
+    let a = Matrix::from_rows(&[vec![0.1, 0.2], vec![0.3, 0.6], vec![0.7, 0.1]]);
+    let x = Matrix::from_rows(&[vec![1.0f64, -3.0], vec![2.0, 0.5]]);
+    let b = gen_matrix_mult(&a, &x);
+    let solution = solve_matrix_tol(&a, &b, 1e-12);
+    assert!(solution.all_consistent());
+    let found = solution.solution().unwrap();
+    assert!(found.data.iter().zip(x.data.iter()).all(|(p, q)| (p - q).abs() < 1e-12));
+    let right = solve_matrix_right_tol(&Matrix::from_rows(&[vec![2.0, 0.0], vec![0.0, 4.0]]), &Matrix::from_rows(&[vec![1.0, 1.0]]), 1e-12);
+    assert_eq!(right.solution().unwrap().data, vec![0.5, 0.25]);
+}
@@ -0,0 +1,91 @@
+use luna::{core::complex::Complex, linalg::matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult}};
+
+fn max_diff(a: &Matrix<f64>, b: &Matrix<f64>) -> f64 {
+    a.data.iter().zip(b.data.iter()).map(|(x, y)| (x - y).abs()).fold(0.0, f64::max)
+}
+
+fn complex(a: &Matrix<f64>, im: f64) -> Matrix<Complex<f64>> {
+    Matrix::from_fn(a.rows, a.cols, |row, col| Complex { re: a[(row, col)], im })
+}
+
+fn hilbert(size: usize, scale: f64) -> Matrix<f64> {
+    Matrix::from_fn(size, size, |row, col| scale / (row + col + 1) as f64)
+}
+
+#[test]
+fn sqrtm_of_spd_hilbert_matrices() {
+    for size in 2..=8 {
+        for scale in [1.0, 10.0] {
+            let a = hilbert(size, scale);
+            let root = a.sqrtm().expect("SPD matrices have a principal square root");
+            let residual = max_diff(&gen_matrix_mult(&root, &root), &a);
+            assert!(residual <= 1e-10 * scale, "size {size} scale {scale}: residual {residual}");
+        }
+    }
+}
+
+#[test]
+fn logm_inverts_expm_on_spd_hilbert_matrices() {
+    for size in 2..=5 {
+        let a = hilbert(size, 1.0);
+        let log = a.logm().expect("SPD matrices have a principal logarithm");
+        assert!(max_diff(&log.expm().unwrap(), &a) <= 1e-9, "size {size}");
+    }
+}
+
+#[test]
+fn sqrtm_of_jordan_block() {
+    let a = Matrix::from_rows(&[vec![4.0, 1.0, 0.0], vec![0.0, 4.0, 1.0], vec![0.0, 0.0, 4.0]]);
+    let root = a.sqrtm().unwrap();
+    // The root of a Jordan block is the upper triangular Toeplitz matrix of sqrt's Taylor series.
+    let expected = Matrix::from_rows(&[vec![2.0, 0.25, -1.0 / 64.0], vec![0.0, 2.0, 0.25], vec![0.0, 0.0, 2.0]]);
+    assert!(max_diff(&root, &expected) <= 1e-14);
+    assert!(a.logm().is_some());
+}
+
+#[test]
+fn sqrtm_with_negative_eigenvalue() {
+    let a = Matrix::from_rows(&[vec![-4.0, 1.0], vec![0.0, 9.0]]);
+    assert!(a.sqrtm().is_none());
+    assert!(a.logm().is_none());
+    // The principal root is undefined on the negative real axis, for complex matrices too.
+    assert!(complex(&a, 0.0).sqrtm().is_none());
+    assert!(complex(&a, 0.0).logm().is_none());
+}
+
+#[test]
+fn complex_sqrtm_near_negative_real_axis_is_principal() {
+    // Just below the axis the principal root of -4 - 1e-4 i is close to -2i, not 2i.
+    for im in [1e-4, -1e-4, 0.5, -0.5] {
+        let a = Matrix::from_rows(&[vec![-4.0, 1.0], vec![0.0, 9.0]]);
+        let mut a = complex(&a, 0.0);
+        a[(0, 0)].im = im;
+        let root = a.sqrtm().expect("the complex principal square root exists");
+        let square = gen_matrix_mult(&root, &root);
+        assert!(square.data.iter().zip(a.data.iter()).all(|(x, y)| (*x - *y).abs() <= 1e-12), "im {im}");
+        assert!(root[(0, 0)].re > 0.0, "im {im}");
+        assert_eq!(root[(0, 0)].im.signum(), im.signum());
+        assert!((root[(1, 1)] - Complex { re: 3.0, im: 0.0 }).abs() <= 1e-12);
+    }
+}
+
+#[test]
+fn expm_of_nilpotent_and_diagonal_matrices() {
+    let nilpotent = Matrix::from_rows(&[vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0], vec![0.0, 0.0, 0.0]]);
+    let expected = Matrix::from_rows(&[vec![1.0, 1.0, 0.5], vec![0.0, 1.0, 1.0], vec![0.0, 0.0, 1.0]]);
+    assert!(max_diff(&nilpotent.expm().unwrap(), &expected) <= 1e-15);
+    // Large enough to need scaling and squaring.
+    let diagonal = Matrix::from_diagonal(&[-3.0, 0.0, 20.0]);
+    let exp = diagonal.expm().unwrap();
+    for (i, val) in [-3.0f64, 0.0, 20.0].iter().enumerate() {
+        assert!((exp[(i, i)] - val.exp()).abs() <= 1e-13 * val.exp());
+    }
+    let rotation = complex(&Matrix::from_rows(&[vec![0.0, 1.0], vec![-1.0, 0.0]]), 0.0).expm().unwrap();
+    assert!((rotation[(0, 1)] - Complex { re: 1.0f64.sin(), im: 0.0 }).abs() <= 1e-15);
+}
+
+#[test]
+fn sqrtm_of_singular_nilpotent_has_no_root() {
+    let a = Matrix::from_rows(&[vec![0.0, 1.0], vec![0.0, 0.0]]);
+    assert!(a.sqrtm().is_none());
+}
@@ -0,0 +1,100 @@
+use luna::{core::{modp::ModP, number::{Field, Ring}}, linalg::matrix::matrix::Matrix};
+
+// The largest primes below 2^63 and 2^64.
+const P63: u64 = 9_223_372_036_854_775_783;
+const P64: u64 = 18_446_744_073_709_551_557;
+
+fn values(p: u64) -> Vec<u64> {
+    let mut seed = 0x9e3779b97f4a7c15u64;
+    let mut out = vec![0, 1, 2, p - 1, p - 2, p / 2, p / 2 + 1];
+    for _ in 0..200 {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        out.push(seed % p);
+    }
+    out
+}
+
+fn check_against_reference<const P: u64>() {
+    let p = P as u128;
+    for a in values(P) {
+        for b in values(P).into_iter().step_by(7) {
+            let (x, y) = (ModP::<P>::new(a), ModP::<P>::new(b));
+            assert_eq!((x + y).value() as u128, (a as u128 + b as u128) % p);
+            assert_eq!((x - y).value() as u128, (a as u128 + p - b as u128) % p);
+            assert_eq!((x * y).value() as u128, (a as u128 * b as u128) % p);
+        }
+        let x = ModP::<P>::new(a);
+        assert_eq!((-x).value() as u128, (p - a as u128) % p);
+        if a != 0 {
+            assert_eq!((x * x.inverse()).value(), 1);
+            assert_eq!((ModP::<P>::one() / x * x).value(), 1);
+        }
+    }
+}
+
+#[test]
+fn arithmetic_matches_reference_near_two_to_the_63() {
+    check_against_reference::<P63>();
+}
+
+#[test]
+fn arithmetic_matches_reference_near_two_to_the_64() {
+    check_against_reference::<P64>();
+}
+
+#[test]
+fn arithmetic_matches_reference_for_small_prime() {
+    check_against_reference::<1_000_003>();
+}
+
+#[test]
+fn every_nonzero_residue_has_its_inverse() {
+    for a in 1..101u64 {
+        let x = ModP::<101>::new(a);
+        let inverse = x.inverse();
+        assert_eq!(inverse.value() * a % 101, 1);
+        assert_eq!(inverse, x.pow(99));
+    }
+    assert_eq!(ModP::<2>::one().inverse(), ModP::<2>::one());
+}
+
+#[test]
+#[should_panic]
+fn zero_has_no_inverse() {
+    ModP::<101>::zero().inverse();
+}
+
+#[test]
+fn conversions_reduce_to_canonical_representatives() {
+    assert_eq!(ModP::<7>::from(0u64).value(), 0);
+    assert_eq!(ModP::<7>::from(0i64).value(), 0);
+    assert_eq!(ModP::<7>::from(15u64).value(), 1);
+    assert_eq!(ModP::<7>::from(-1i64).value(), 6);
+    assert_eq!(ModP::<7>::from(-14i64).value(), 0);
+    assert_eq!(ModP::<P63>::from(i64::MIN).value(), (i64::MIN as i128).rem_euclid(P63 as i128) as u64);
+    assert_eq!(ModP::<P64>::from(u64::MAX).value(), u64::MAX - P64);
+    assert!(ModP::<P64>::from(-1i64).valid());
+}
+
+#[test]
+fn pow_matches_repeated_multiplication() {
+    let x = ModP::<P64>::new(123_456_789_123_456_789);
+    let mut acc = ModP::<P64>::one();
+    for exp in 0..40 {
+        assert_eq!(x.pow(exp), acc);
+        acc *= x;
+    }
+    // Fermat's little theorem.
+    assert_eq!(x.pow(P64 - 1), ModP::one());
+}
+
+#[test]
+fn exact_algorithms_work_over_gf_p() {
+    let m = Matrix::from_fn(3, 3, |row, col| ModP::<13>::from([[2i64, -3, 1], [2, 0, -1], [1, 4, 5]][row][col]));
+    // The integer determinant is 49, which is 10 modulo 13.
+    assert_eq!(m.determinant_bareiss().value(), 10);
+    let singular = Matrix::from_fn(3, 3, |row, col| ModP::<13>::from((row * 3 + col + 1) as u64));
+    assert_eq!(singular.rank_bareiss(), 2);
+}
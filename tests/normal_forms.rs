@@ -0,0 +1,118 @@
+use luna::linalg::{matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult}, solvers::diophantine_solver::solve_diophantine_system};
+
+fn wide(a: &Matrix<i64>) -> Matrix<i128> {
+    Matrix::new(a.rows, a.cols, a.data.iter().map(|val| *val as i128).collect())
+}
+
+fn same(a: &Matrix<i128>, b: &Matrix<i64>) -> bool {
+    a.data.iter().zip(b.data.iter()).all(|(x, y)| *x == *y as i128)
+}
+
+// A unimodular matrix has the identity as its Hermite form.
+fn unimodular(a: &Matrix<i64>) -> bool {
+    let h = a.hermite_normal_form().unwrap().h;
+    h.iter_indexed().all(|((row, col), val)| *val == if row == col { 1 } else { 0 })
+}
+
+fn random_matrices(rows: usize, cols: usize, bound: i64, count: usize) -> Vec<Matrix<i64>> {
+    let mut seed = 0x2545f4914f6cdd1du64;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % (2 * bound as u64 + 1)) as i64 - bound
+    };
+    (0..count).map(|_| Matrix::from_fn(rows, cols, |_, _| next())).collect()
+}
+
+fn check_smith(a: &Matrix<i64>) {
+    let s = a.smith_normal_form().expect("small inputs stay within i64");
+    assert!(same(&gen_matrix_mult(&gen_matrix_mult(&wide(&s.u), &wide(a)), &wide(&s.v)), &s.d));
+    assert!(s.d.iter_indexed().all(|((row, col), val)| row == col || *val == 0));
+    let factors = s.invariant_factors();
+    assert!(factors.iter().all(|val| *val > 0));
+    assert!(factors.windows(2).all(|pair| pair[1] % pair[0] == 0));
+    assert!(s.d.diagonal_iter().skip(factors.len()).all(|val| *val == 0));
+    assert!(unimodular(&s.u) && unimodular(&s.v));
+}
+
+fn check_hermite(a: &Matrix<i64>) {
+    let h = a.hermite_normal_form().expect("small inputs stay within i64");
+    assert!(same(&gen_matrix_mult(&wide(&h.u), &wide(a)), &h.h));
+    assert!(unimodular(&h.u));
+    for (row, col) in h.pivots.iter().enumerate() {
+        let pivot = h.h[(row, *col)];
+        assert!(pivot > 0);
+        assert!((0..row).all(|above| (0..pivot).contains(&h.h[(above, *col)])));
+        assert!((row+1..a.rows).all(|below| h.h[(below, *col)] == 0));
+        assert!((0..*col).all(|left| h.h[(row, left)] == 0));
+    }
+    assert!((h.rank()..a.rows).all(|row| (0..a.cols).all(|col| h.h[(row, col)] == 0)));
+}
+
+#[test]
+fn smith_form_of_known_matrix() {
+    let a = Matrix::from_rows(&[vec![2, 4, 4], vec![-6, 6, 12], vec![10, -4, -16]]);
+    let s = a.smith_normal_form().unwrap();
+    assert_eq!(s.invariant_factors(), vec![2, 6, 12]);
+    check_smith(&a);
+}
+
+#[test]
+fn smith_forms_of_random_matrices() {
+    for a in random_matrices(4, 4, 100, 100) {
+        check_smith(&a);
+    }
+    for a in random_matrices(3, 6, 100, 30).into_iter().chain(random_matrices(6, 3, 100, 30)) {
+        check_smith(&a);
+    }
+}
+
+#[test]
+fn smith_form_of_rank_deficient_matrix() {
+    let a = Matrix::from_rows(&[vec![1, 2, 3, 4], vec![2, 4, 6, 8], vec![3, 5, 7, 9]]);
+    let s = a.smith_normal_form().unwrap();
+    assert_eq!(s.invariant_factors(), vec![1, 1]);
+    check_smith(&a);
+}
+
+#[test]
+fn hermite_forms_of_random_matrices() {
+    for a in random_matrices(8, 8, 100, 30) {
+        check_hermite(&a);
+    }
+    for a in random_matrices(4, 7, 100, 30).into_iter().chain(random_matrices(7, 4, 100, 30)) {
+        check_hermite(&a);
+    }
+}
+
+#[test]
+fn overflow_is_reported() {
+    // Consecutive integers are coprime, so d_2 would be their product.
+    let a = Matrix::from_rows(&[vec![i64::MAX, 0], vec![0, i64::MAX - 1]]);
+    assert!(a.smith_normal_form().is_err());
+    assert!(a.hermite_normal_form().is_ok());
+}
+
+#[test]
+fn diophantine_system_with_kernel() {
+    // 2x + 3y + 5z = 7 and 4x - y + z = 3
+    let aug = Matrix::from_rows(&[vec![2, 3, 5, 7], vec![4, -1, 1, 3]]);
+    let solution = solve_diophantine_system(&aug).unwrap().expect("the system has integer solutions");
+    assert_eq!(solution.kernel.len(), 1);
+    for params in [[0], [1], [-3]] {
+        let x = solution.sample(&params);
+        assert_eq!(2 * x[0] + 3 * x[1] + 5 * x[2], 7);
+        assert_eq!(4 * x[0] - x[1] + x[2], 3);
+    }
+    // The integer null space is spanned by the primitive cross product of the rows.
+    let k = &solution.kernel[0];
+    assert!(*k == vec![4, 9, -7] || *k == vec![-4, -9, 7]);
+}
+
+#[test]
+fn diophantine_system_without_integer_solution() {
+    assert!(solve_diophantine_system(&Matrix::from_rows(&[vec![2, 1]])).unwrap().is_none());
+    assert!(solve_diophantine_system(&Matrix::from_rows(&[vec![4, 6, 3]])).unwrap().is_none());
+    assert!(solve_diophantine_system(&Matrix::from_rows(&[vec![1, 1, 1], vec![1, 1, 2]])).unwrap().is_none());
+}
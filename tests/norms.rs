@@ -0,0 +1,112 @@
+use luna::{core::{approx_eq::ApproxEq, complex::Complex, rational::Rational}, linalg::matrix::{matrix::Matrix, matrix_mult::gen_matrix_mult, matrix_transpose::gen_matrix_transpose}};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn random_matrices(size: usize, count: usize) -> Vec<Matrix<f64>> {
+    let mut seed = 0x9e3779b97f4a7c15u64;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % 2001) as f64 / 100.0 - 10.0
+    };
+    (0..count).map(|_| Matrix::from_fn(size, size, |_, _| next())).collect()
+}
+
+fn hilbert(size: usize) -> Matrix<f64> {
+    Matrix::from_fn(size, size, |row, col| 1.0 / (row + col + 1) as f64)
+}
+
+#[test]
+fn entrywise_and_induced_norms() {
+    let a = Matrix::from_rows(&[vec![1.0, -7.0], vec![-2.0, -3.0], vec![4.0, 0.5]]);
+    assert_eq!(a.norm_1(), 10.5);
+    assert_eq!(a.norm_inf(), 8.0);
+    assert_eq!(a.norm_max(), 7.0);
+    assert!(a.norm_frobenius().approx_eq(&79.25f64.sqrt()));
+
+    let z = Matrix::from_rows(&[vec![Complex { re: 3.0, im: 4.0 }, Complex { re: 0.0, im: 1.0 }]]);
+    assert_eq!(z.norm_1(), 5.0);
+    assert_eq!(z.norm_inf(), 6.0);
+    assert_eq!(z.norm_max(), 5.0);
+    assert!(z.norm_frobenius().approx_eq(&26f64.sqrt()));
+    assert_eq!(Matrix::<f64>::zeroes(0, 3).norm_1(), 0.0);
+}
+
+#[test]
+fn spectral_norm() {
+    // A^T * A has eigenvalues 45 and 5.
+    let a = Matrix::from_rows(&[vec![3.0, 0.0], vec![4.0, 5.0]]);
+    assert!(a.norm_2().approx_eq(&45f64.sqrt()));
+    assert!(Matrix::from_diagonal(&[2.0, -9.0, 4.0]).norm_2().approx_eq(&9.0));
+    assert_eq!(Matrix::<f64>::zeroes(3, 2).norm_2(), 0.0);
+    // A rank one matrix u * v^T has norm |u| * |v|.
+    let outer = Matrix::from_fn(3, 4, |row, col| (row + 1) as f64 * (col as f64 - 1.5));
+    assert!(outer.norm_2().approx_eq(&(14f64.sqrt() * 5f64.sqrt())));
+    for m in random_matrices(5, 10) {
+        let sigma = m.norm_2();
+        assert!(sigma <= m.norm_frobenius() * (1.0 + 1e-12));
+        assert!(sigma * sigma <= m.norm_1() * m.norm_inf() * (1.0 + 1e-12));
+        assert!(sigma >= m.norm_max());
+    }
+}
+
+#[test]
+fn lu_factors_reproduce_matrix() {
+    for a in random_matrices(6, 10) {
+        let lu = a.lu();
+        let pa = lu.perm.apply_rows(&a);
+        let product = gen_matrix_mult(&lu.l(), &lu.u());
+        assert!(product.data.iter().zip(pa.data.iter()).all(|(x, y)| (x - y).abs() < 1e-10));
+        // Partial pivoting keeps the multipliers at most one.
+        assert!(lu.l().data.iter().all(|val| val.abs() <= 1.0));
+    }
+}
+
+#[test]
+fn exact_lu() {
+    let a = Matrix::from_fn(4, 4, |row, col| r([[0, 2, -1, 3], [1, 1, 0, 2], [4, -3, 2, 1], [2, 0, 5, -2]][row][col]));
+    let lu = a.lu();
+    assert_eq!(gen_matrix_mult(&lu.l(), &lu.u()).data, lu.perm.apply_rows(&a).data);
+    assert_eq!(lu.determinant(), a.determinant_bareiss());
+    let b = vec![r(1), r(-2), r(0), r(5)];
+    let x = lu.solve(&b).unwrap();
+    assert_eq!(gen_matrix_mult(&a, &Matrix::new(4, 1, x)).data, b);
+    let y = lu.solve_transpose(&b).unwrap();
+    assert_eq!(gen_matrix_mult(&gen_matrix_transpose(&a), &Matrix::new(4, 1, y)).data, b);
+    let inverse = lu.inverse().unwrap();
+    assert_eq!(gen_matrix_mult(&a, &inverse).data, Matrix::<Rational>::identity(4).data);
+}
+
+#[test]
+fn singular_lu() {
+    let a = Matrix::from_fn(3, 3, |row, col| r((row * 3 + col) as i64));
+    let lu = a.lu();
+    assert!(lu.is_singular());
+    assert_eq!(lu.determinant(), r(0));
+    assert!(lu.solve(&[r(1), r(0), r(0)]).is_none());
+    assert!(lu.inverse().is_none());
+    assert!(Matrix::from_fn(3, 3, |row, col| (row * 3 + col) as f64 - 4.0).condition_number_1_estimate().is_none_or(|c| c > 1e15));
+    assert!(Matrix::<f64>::zeroes(2, 2).condition_number_1().is_none());
+}
+
+#[test]
+fn condition_estimates() {
+    let d = Matrix::from_diagonal(&[1.0, 1e-3, 10.0]);
+    assert!(d.condition_number_1().unwrap().approx_eq(&1e4));
+    assert!(d.condition_number_1_estimate().unwrap().approx_eq(&1e4));
+    let mut matrices = random_matrices(6, 20);
+    matrices.push(hilbert(6));
+    for a in matrices {
+        let exact = a.condition_number_1().unwrap();
+        let estimate = a.condition_number_1_estimate().unwrap();
+        assert!(estimate <= exact * (1.0 + 1e-8));
+        assert!(estimate >= exact / 3.0);
+        assert!(exact >= 1.0);
+    }
+    // The 6x6 Hilbert matrix has a 1-norm condition number near 2.9e7.
+    let h = hilbert(6).condition_number_1_estimate().unwrap();
+    assert!(h > 2.8e7 && h < 3.0e7);
+}
@@ -0,0 +1,108 @@
+use luna::{core::{complex::Complex, modp::ModP, number::Field, rational::Rational}, linalg::matrix::{matrix::Matrix, matrix_add::gen_matrix_add, matrix_mult::gen_matrix_mult, matrix_sub::gen_matrix_sub}};
+
+fn a() -> Matrix<i64> {
+    Matrix::from_rows(&[vec![1, 2], vec![3, 4], vec![5, 6]])
+}
+
+fn b() -> Matrix<i64> {
+    Matrix::from_rows(&[vec![-1, 0], vec![2, 7], vec![1, -3]])
+}
+
+fn c() -> Matrix<i64> {
+    Matrix::from_rows(&[vec![2, 0, 1], vec![-1, 3, 4]])
+}
+
+#[test]
+fn owned_and_borrowed_add_sub() {
+    let sum = gen_matrix_add(&a(), &b());
+    let diff = gen_matrix_sub(&a(), &b());
+    assert_eq!(sum.data, vec![0, 2, 5, 11, 6, 3]);
+    assert_eq!((&a() + &b()).data, sum.data);
+    assert_eq!((a() + &b()).data, sum.data);
+    assert_eq!((&a() + b()).data, sum.data);
+    assert_eq!((a() + b()).data, sum.data);
+    assert_eq!((&a() - &b()).data, diff.data);
+    assert_eq!((a() - &b()).data, diff.data);
+    assert_eq!((&a() - b()).data, diff.data);
+    assert_eq!((a() - b()).data, diff.data);
+    assert_eq!((-&a()).data, vec![-1, -2, -3, -4, -5, -6]);
+    assert_eq!((-a()).data, (-&a()).data);
+
+    let mut m = a();
+    m += &b();
+    assert_eq!(m.data, sum.data);
+    m -= b();
+    assert_eq!(m.data, a().data);
+    m += b();
+    m -= &b();
+    assert_eq!(m.data, a().data);
+}
+
+#[test]
+fn owned_and_borrowed_mult() {
+    let prod = gen_matrix_mult(&a(), &c());
+    assert_eq!(prod.data, vec![0, 6, 9, 2, 12, 19, 4, 18, 29]);
+    assert_eq!((&a() * &c()).data, prod.data);
+    assert_eq!((a() * &c()).data, prod.data);
+    assert_eq!((&a() * c()).data, prod.data);
+    assert_eq!((a() * c()).data, prod.data);
+    let mut m = a();
+    m *= &c();
+    assert_eq!(m.data, prod.data);
+    let mut m = c();
+    m *= a();
+    assert_eq!(m.data, vec![7, 10, 28, 34]);
+}
+
+#[test]
+fn scalar_operators() {
+    assert_eq!((&a() * 3).data, vec![3, 6, 9, 12, 15, 18]);
+    assert_eq!((a() * 3).data, (3 * &a()).data);
+    assert_eq!((3 * a()).data, (&a() * 3).data);
+    let mut m = a();
+    m *= -2;
+    assert_eq!(m.data, vec![-2, -4, -6, -8, -10, -12]);
+
+    let half = Rational { pos: true, num: 1, den: 2 };
+    let q = a().map(|val| Rational { pos: true, num: val as u64, den: 1 });
+    assert_eq!((&q / half).data, (Rational { pos: true, num: 2, den: 1 } * &q).data);
+    let mut m = q.clone();
+    m /= half;
+    m *= half;
+    assert_eq!(m.data, q.data);
+    assert_eq!((q.clone() / half).data, (half.inverse() * q).data);
+
+    let z = Complex { re: 0.0, im: 1.0 };
+    let rotated = z * &Matrix::from_rows(&[vec![Complex { re: 1.0, im: 0.0 }]]);
+    assert!(rotated[(0, 0)].re == 0.0 && rotated[(0, 0)].im == 1.0);
+    let three = ModP::<5>::new(3);
+    assert_eq!((three * Matrix::from_rows(&[vec![ModP::<5>::new(4)]]))[(0, 0)], ModP::new(2));
+}
+
+#[test]
+fn matrix_vector_operators() {
+    let v = vec![2, -1];
+    let expected = vec![0, 2, 4];
+    assert_eq!(&a() * &v[..], expected);
+    assert_eq!(a() * &v[..], expected);
+    assert_eq!(&a() * &v, expected);
+    assert_eq!(a() * &v, expected);
+}
+
+#[test]
+fn flat_and_row_indexing() {
+    let mut m = a();
+    assert_eq!(m[3], 4);
+    assert_eq!(m[3], m[(1, 1)]);
+    m[5] = 60;
+    assert_eq!(m[(2, 1)], 60);
+    assert_eq!(&m.by_rows()[1], &[3, 4]);
+    m.by_rows_mut()[0][1] = 20;
+    assert_eq!(m.row(0), &[1, 20]);
+}
+
+#[test]
+#[should_panic]
+fn mismatched_add_is_rejected() {
+    let _ = a() + c();
+}
@@ -0,0 +1,91 @@
+use luna::linalg::{matrix::{matrix::Matrix, matrix_elementary::RowOp, matrix_mult::gen_matrix_mult, matrix_transpose::gen_matrix_transpose}, permutation::Permutation};
+
+// Every permutation of 0..size, in lexicographic order.
+fn all_permutations(size: usize) -> Vec<Permutation> {
+    if size == 0 {
+        return vec![Permutation::identity(0)];
+    }
+    let mut out = Vec::new();
+    for smaller in all_permutations(size - 1) {
+        for pos in 0..size {
+            let mut indices = smaller.indices.clone();
+            indices.insert(pos, size - 1);
+            out.push(Permutation::from_indices(indices));
+        }
+    }
+    out
+}
+
+fn sample(rows: usize, cols: usize) -> Matrix<i64> {
+    Matrix::from_fn(rows, cols, |row, col| (row * 10 + col) as i64)
+}
+
+#[test]
+fn applying_matches_dense_products() {
+    let a = sample(4, 3);
+    let b = sample(3, 4);
+    for p in all_permutations(4) {
+        let dense = p.to_matrix::<i64>();
+        assert_eq!(p.apply_rows(&a).data, gen_matrix_mult(&dense, &a).data);
+        assert_eq!(p.apply_cols(&b).data, gen_matrix_mult(&b, &gen_matrix_transpose(&dense)).data);
+        let v: Vec<i64> = (0..4).map(|i| i * i - 3).collect();
+        let column = Matrix::new(4, 1, v.clone());
+        assert_eq!(p.apply_vec(&v), gen_matrix_mult(&dense, &column).data);
+        let mut inplace = v.clone();
+        p.apply_vec_inplace(&mut inplace);
+        assert_eq!(inplace, p.apply_vec(&v));
+    }
+}
+
+#[test]
+fn composition_and_inverse() {
+    let perms = all_permutations(4);
+    for p in perms.iter() {
+        assert!(p.compose(&p.inverse()).is_identity());
+        assert!(p.inverse().compose(p).is_identity());
+        assert_eq!(gen_matrix_transpose(&p.to_matrix::<i64>()).data, p.inverse().to_matrix::<i64>().data);
+        for q in perms.iter().step_by(5) {
+            let dense = gen_matrix_mult(&p.to_matrix::<i64>(), &q.to_matrix::<i64>());
+            assert_eq!((p * q).to_matrix::<i64>().data, dense.data);
+        }
+    }
+}
+
+#[test]
+fn sign_matches_determinant() {
+    let perms = all_permutations(4);
+    assert_eq!(perms.iter().filter(|p| p.is_even()).count(), 12);
+    for p in perms.iter() {
+        assert_eq!(p.sign::<i64>(), p.to_matrix::<i64>().determinant_bareiss());
+    }
+    assert_eq!(Permutation::transposition(5, 1, 3).sign::<i64>(), -1);
+}
+
+#[test]
+fn cycle_decomposition() {
+    let p = Permutation::from_indices(vec![2, 0, 1, 3, 5, 4]);
+    assert_eq!(p.cycles(), vec![vec![0, 2, 1], vec![3], vec![4, 5]]);
+    assert!(!p.is_even());
+    assert_eq!(p.to_string(), "(0 2 1)(4 5)");
+    assert_eq!(Permutation::identity(3).to_string(), "()");
+}
+
+#[test]
+fn recorded_row_swaps() {
+    let ops = [RowOp::Swap(0, 2), RowOp::Scale(1, 3), RowOp::Swap(1, 2), RowOp::AddMultiple(0, 1, 2)];
+    let p = Permutation::from_row_ops(&ops, 3);
+    let swaps: Vec<RowOp<i64>> = ops.iter().copied().filter(|op| matches!(op, RowOp::Swap(_, _))).collect();
+    assert_eq!(p.to_matrix::<i64>().data, RowOp::sequence_matrix(&swaps, 3).data);
+    let mut a = sample(3, 2);
+    let expected = p.apply_rows(&a);
+    for op in swaps.iter() {
+        op.apply(&mut a);
+    }
+    assert_eq!(a.data, expected.data);
+}
+
+#[test]
+#[should_panic]
+fn repeated_index_is_rejected() {
+    Permutation::from_indices(vec![0, 1, 1]);
+}
@@ -0,0 +1,30 @@
+use luna::{core::complex::Complex, linalg::polynomial::polynomial::Polynomial};
+
+#[test]
+fn wilkinson_roots_are_all_simple() {
+    let expected: Vec<f64> = (1..=20).map(|k| k as f64).collect();
+    let found = Polynomial::from_roots(&expected).roots_with_multiplicity().unwrap();
+    assert_eq!(found.len(), 20);
+    for ((root, multiplicity), expected) in found.iter().zip(expected.iter()) {
+        assert_eq!(*multiplicity, 1);
+        assert!((root.re - expected).abs() <= 1e-2 * expected && root.im.abs() <= 1e-2);
+    }
+}
+
+#[test]
+fn multiple_roots_are_grouped() {
+    let p = Polynomial::from_roots(&[3.0, 3.0, 3.0, 3.0, 3.0, 3.0, -1.0, -1.0, -1.0, 0.5]);
+    let found = p.roots_with_multiplicity().unwrap();
+    let multiplicities: Vec<usize> = found.iter().map(|(_, m)| *m).collect();
+    assert_eq!(multiplicities, vec![3, 1, 6]);
+    for ((root, _), expected) in found.iter().zip([-1.0, 0.5, 3.0]) {
+        assert!((*root - Complex { re: expected, im: 0.0 }).abs() <= 1e-10);
+    }
+}
+
+#[test]
+fn nearby_simple_roots_stay_apart() {
+    let found = Polynomial::from_roots(&[1.0f64, 1.001, 3.0]).roots().unwrap();
+    assert_eq!(found.len(), 3);
+    assert!((found[0].re - 1.0).abs() <= 1e-12 && (found[1].re - 1.001).abs() <= 1e-12);
+}
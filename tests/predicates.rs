@@ -0,0 +1,131 @@
+use luna::{core::{complex::Complex, modp::ModP, rational::Rational}, linalg::matrix::matrix::Matrix};
+
+fn r(num: i64, den: u64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den }
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col], 1))
+}
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex { re, im }
+}
+
+fn complex(rows: &[&[(f64, f64)]]) -> Matrix<Complex<f64>> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| c(rows[row][col].0, rows[row][col].1))
+}
+
+#[test]
+fn symmetry_and_shape() {
+    let zero = r(0, 1);
+    let s = rational(&[&[1, 2, 3], &[2, 5, -1], &[3, -1, 0]]);
+    assert!(s.is_symmetric(zero));
+    assert!(!s.is_skew_symmetric(zero));
+    let k = rational(&[&[0, 2, -3], &[-2, 0, 1], &[3, -1, 0]]);
+    assert!(k.is_skew_symmetric(zero));
+    assert!(!k.is_symmetric(zero));
+    assert!(!rational(&[&[1, 2]]).is_symmetric(zero));
+
+    let u = rational(&[&[1, 2, 3], &[0, 4, 5], &[0, 0, 6]]);
+    assert!(u.is_upper_triangular(zero) && !u.is_lower_triangular(zero) && !u.is_diagonal(zero));
+    assert!(rational(&[&[1, 0], &[7, 2], &[1, 1]]).is_lower_triangular(zero));
+    assert!(rational(&[&[3, 0, 0], &[0, 0, 0]]).is_diagonal(zero));
+}
+
+#[test]
+fn tolerance_absorbs_round_off() {
+    let a = Matrix::from_rows(&[vec![1.0, 0.1 + 0.2], vec![0.3, 1.0]]);
+    assert!(!a.is_symmetric(0.0));
+    assert!(a.is_symmetric(1e-12));
+    let theta: f64 = 0.7;
+    let rotation = Matrix::from_rows(&[vec![theta.cos(), -theta.sin()], vec![theta.sin(), theta.cos()]]);
+    assert!(rotation.is_orthogonal(1e-12));
+    assert!(!Matrix::from_rows(&[vec![1.0, 1.0], vec![0.0, 1.0]]).is_orthogonal(1e-12));
+}
+
+#[test]
+fn exact_orthogonality_and_dominance() {
+    let zero = r(0, 1);
+    // A rational rotation from the 3-4-5 triangle.
+    let q = Matrix::from_rows(&[vec![r(3, 5), r(-4, 5)], vec![r(4, 5), r(3, 5)]]);
+    assert!(q.is_orthogonal(zero));
+    assert!(Matrix::<Rational>::identity(3).is_orthogonal(zero));
+
+    let weak = rational(&[&[3, -1, 2], &[0, 1, 1], &[1, 1, -2]]);
+    assert!(weak.is_diagonally_dominant());
+    assert!(!weak.is_strictly_diagonally_dominant());
+    let strict = rational(&[&[4, -1, 2], &[0, 3, 1], &[1, 1, -3]]);
+    assert!(strict.is_strictly_diagonally_dominant());
+    assert!(!rational(&[&[1, 2], &[0, 1]]).is_diagonally_dominant());
+}
+
+#[test]
+fn real_positive_definiteness() {
+    let zero = r(0, 1);
+    assert!(rational(&[&[2, -1, 0], &[-1, 2, -1], &[0, -1, 2]]).is_positive_definite(zero));
+    // Positive semidefinite and indefinite matrices are rejected.
+    assert!(!rational(&[&[1, 1], &[1, 1]]).is_positive_definite(zero));
+    assert!(!rational(&[&[1, 2], &[2, 1]]).is_positive_definite(zero));
+    // Positive diagonal and eigenvalues are not enough without symmetry.
+    assert!(!rational(&[&[2, 1], &[0, 2]]).is_positive_definite(zero));
+    let hilbert = Matrix::from_fn(5, 5, |row, col| r(1, (row + col + 1) as u64));
+    assert!(hilbert.is_positive_definite(zero));
+    let float_hilbert = Matrix::from_fn(5, 5, |row, col| 1.0 / (row + col + 1) as f64);
+    assert!(float_hilbert.is_positive_definite(0.0));
+}
+
+#[test]
+fn complex_positive_definiteness() {
+    let tol = c(1e-12, 0.0);
+    // Hermitian with eigenvalues 1 and 3.
+    assert!(complex(&[&[(2.0, 0.0), (0.0, 1.0)], &[(0.0, -1.0), (2.0, 0.0)]]).is_positive_definite(tol));
+    // Hermitian with eigenvalues -1 and 3; l * l instead of l * conj(l) would accept it.
+    assert!(!complex(&[&[(1.0, 0.0), (0.0, 2.0)], &[(0.0, -2.0), (1.0, 0.0)]]).is_positive_definite(tol));
+    // Complex symmetric but not Hermitian.
+    assert!(!complex(&[&[(2.0, 0.0), (0.0, 1.0)], &[(0.0, 1.0), (2.0, 0.0)]]).is_positive_definite(tol));
+    // A non-real diagonal entry is not Hermitian.
+    assert!(!complex(&[&[(2.0, 1.0)]]).is_positive_definite(tol));
+    let a = complex(&[&[(4.0, 0.0), (1.0, 2.0), (0.0, -1.0)], &[(1.0, -2.0), (6.0, 0.0), (1.0, 1.0)], &[(0.0, 1.0), (1.0, -1.0), (3.0, 0.0)]]);
+    assert!(a.is_hermitian(1e-12));
+    assert!(a.is_positive_definite(tol));
+}
+
+#[test]
+fn unitary() {
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let u = complex(&[&[(s, 0.0), (0.0, s)], &[(0.0, s), (s, 0.0)]]);
+    assert!(u.is_unitary(1e-12));
+    assert!(!u.is_hermitian(1e-12));
+    assert!(!complex(&[&[(1.0, 0.0), (0.0, 1.0)], &[(0.0, 1.0), (1.0, 0.0)]]).is_unitary(1e-12));
+}
+
+#[test]
+fn idempotent_and_nilpotent() {
+    let zero = r(0, 1);
+    // Projection onto the line y = x.
+    let p = Matrix::from_rows(&[vec![r(1, 2), r(1, 2)], vec![r(1, 2), r(1, 2)]]);
+    assert!(p.is_idempotent(zero));
+    assert!(!rational(&[&[1, 1], &[0, 2]]).is_idempotent(zero));
+    let shift = Matrix::from_fn(5, 5, |row, col| r((col == row + 1) as i64, 1));
+    assert!(shift.is_nilpotent(zero));
+    assert!(!shift.is_idempotent(zero));
+    assert!(!Matrix::<Rational>::identity(3).is_nilpotent(zero));
+    let m = Matrix::from_fn(3, 3, |row, col| ModP::<7>::new(if col > row { (row + col) as u64 } else { 0 }));
+    assert!(m.is_nilpotent(ModP::new(0)));
+}
+
+#[test]
+fn reduced_row_echelon_form() {
+    let zero = r(0, 1);
+    assert!(rational(&[&[1, 0, 2, 0], &[0, 1, -1, 0], &[0, 0, 0, 1]]).is_in_rref(zero));
+    assert!(rational(&[&[0, 1, 5], &[0, 0, 0]]).is_in_rref(zero));
+    // A zero row above a nonzero one, a leading 2, an entry above a leading 1, and leads out of order.
+    assert!(!rational(&[&[0, 0, 0], &[1, 0, 0]]).is_in_rref(zero));
+    assert!(!rational(&[&[2, 0], &[0, 1]]).is_in_rref(zero));
+    assert!(!rational(&[&[1, 3], &[0, 1]]).is_in_rref(zero));
+    assert!(!rational(&[&[0, 1], &[1, 0]]).is_in_rref(zero));
+    let mut a = rational(&[&[2, 4, -2], &[1, 3, 0], &[3, 7, -2]]);
+    a.reduced_row_echelon_form();
+    assert!(a.is_in_rref(zero));
+}
@@ -0,0 +1,39 @@
+use luna::core::rational::Rational;
+
+fn r(num: i64, den: u64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den }
+}
+
+#[test]
+fn mixed_sign_addition() {
+    assert!(r(1, 2) + r(-1, 3) == r(1, 6));
+    assert!(r(-1, 2) + r(1, 3) == r(-1, 6));
+    assert!(r(1, 3) + r(-1, 2) == r(-1, 6));
+    assert!(r(-1, 3) + r(1, 2) == r(1, 6));
+    assert!(r(3, 4) + r(-3, 4) == r(0, 1));
+}
+
+#[test]
+fn ordering_matches_values() {
+    let values = [r(-7, 2), r(-1, 3), r(0, 1), r(1, 4), r(2, 7), r(5, 1)];
+    for (i, a) in values.iter().enumerate() {
+        for (j, b) in values.iter().enumerate() {
+            assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)));
+            assert_eq!(a < b, i < j);
+            assert_eq!(a >= b, i >= j);
+        }
+    }
+    assert!(r(2, 4) == r(1, 2) && r(2, 4) <= r(1, 2));
+}
+
+#[test]
+fn ordering_of_large_values() {
+    let big = |pos: bool, num: u64, den: u64| Rational { pos, num, den };
+    let (a, b) = (big(true, u64::MAX, u64::MAX - 1), big(true, u64::MAX - 1, u64::MAX - 2));
+    // (M) / (M - 1) < (M - 1) / (M - 2), whose cross products overflow i128 when signed.
+    assert_eq!(a.partial_cmp(&b), Some(std::cmp::Ordering::Less));
+    assert!(a != b);
+    assert!(big(false, u64::MAX, 1) < big(true, 1, u64::MAX));
+    assert!(big(false, u64::MAX, u64::MAX - 1) > big(false, u64::MAX - 1, u64::MAX - 2));
+    assert_eq!(big(true, 0, 3).partial_cmp(&big(false, 0, 5)), Some(std::cmp::Ordering::Equal));
+}
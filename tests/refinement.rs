@@ -0,0 +1,82 @@
+use luna::linalg::{matrix::{matrix::Matrix, matrix_vector_mult::gen_matrix_vector_mult}, solvers::{refinement_solver::{solve_linear_system_refined, solve_report, RefinementOptions}, row_reduction_solver::solve_linear_system_rref}};
+
+// The augmented system [A | A * x] for the n x n Hilbert matrix and x = (1, -1, 1, ...).
+fn hilbert_system(size: usize) -> (Matrix<f64>, Vec<f64>) {
+    let a = Matrix::from_fn(size, size, |row, col| 1.0 / (row + col + 1) as f64);
+    let x: Vec<f64> = (0..size).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+    let b = gen_matrix_vector_mult(&a, &x);
+    (Matrix::hstack(&[&a, &Matrix::new(size, 1, b)]), x)
+}
+
+fn relative_error(x: &[f64], truth: &[f64]) -> f64 {
+    let diff = x.iter().zip(truth.iter()).fold(0.0f64, |acc, (a, b)| acc.max((a - b).abs()));
+    diff / truth.iter().fold(0.0f64, |acc, val| acc.max(val.abs()))
+}
+
+#[test]
+fn refinement_reduces_residual() {
+    let (aug, truth) = hilbert_system(8);
+    let plain = solve_report(&aug, &solve_linear_system_rref(&aug).unwrap());
+    let refined = solve_linear_system_refined(&aug, RefinementOptions::default()).unwrap();
+    assert!(refined.refinement_steps >= 1);
+    assert!(refined.residual_norm <= plain.residual_norm);
+    assert!(refined.backward_error < 1e-15);
+    // The estimate bounds the true forward error.
+    let error = relative_error(&refined.solution, &truth);
+    assert!(error <= refined.forward_error_estimate.unwrap());
+    assert!(error < 1e-5);
+}
+
+#[test]
+fn extended_residuals_for_f32() {
+    let (aug, _) = hilbert_system(5);
+    let narrow = aug.map(|val| val as f32);
+    let options = RefinementOptions { max_steps: 20, extended_residuals: true };
+    let extended = solve_linear_system_refined(&narrow, options).unwrap();
+    let plain = solve_linear_system_refined(&narrow, RefinementOptions { extended_residuals: false, ..options }).unwrap();
+    let wide = |x: &[f32]| x.iter().map(|val| *val as f64).collect::<Vec<f64>>();
+    // Residuals in f64 recover the solution of the rounded system to nearly f32 precision.
+    let narrow_truth = solve_linear_system_refined(&narrow.map(|val| val as f64), RefinementOptions::default()).unwrap().solution;
+    let extended_error = relative_error(&wide(&extended.solution), &narrow_truth);
+    let plain_error = relative_error(&wide(&plain.solution), &narrow_truth);
+    assert!(extended_error <= plain_error);
+    assert!(extended_error < 1e-6);
+    assert!(extended_error <= extended.forward_error_estimate.unwrap() as f64);
+}
+
+#[test]
+fn report_on_exact_solution() {
+    let aug = Matrix::from_rows(&[vec![2.0, 1.0, 5.0], vec![1.0, 3.0, 10.0]]);
+    let report = solve_report(&aug, &[1.0, 3.0]);
+    assert_eq!(report.residual_norm, 0.0);
+    assert_eq!(report.backward_error, 0.0);
+    assert_eq!(report.refinement_steps, 0);
+    assert!(report.forward_error_estimate.unwrap() < 1e-14);
+
+    // A wrong answer shows up in the residual.
+    let wrong = solve_report(&aug, &[1.0, 2.0]);
+    assert_eq!(wrong.residual_norm, 3.0);
+    assert!(wrong.backward_error > 0.1);
+
+    // Least squares style rectangular systems get no forward estimate.
+    let tall = Matrix::from_rows(&[vec![1.0, 1.0], vec![1.0, 2.0], vec![1.0, 3.0]]);
+    let report = solve_report(&tall, &[2.0]);
+    assert!(report.forward_error_estimate.is_none());
+    assert_eq!(report.residual_norm, 1.0);
+}
+
+#[test]
+fn singular_systems() {
+    let aug = Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0]]);
+    assert!(solve_linear_system_refined(&aug, RefinementOptions::default()).is_none());
+    assert!(solve_report(&aug, &[1.0, 1.0]).forward_error_estimate.is_none());
+}
+
+#[test]
+fn zero_steps_returns_lu_solution() {
+    let (aug, _) = hilbert_system(4);
+    let report = solve_linear_system_refined(&aug, RefinementOptions { max_steps: 0, extended_residuals: false }).unwrap();
+    assert_eq!(report.refinement_steps, 0);
+    let lu_solution = Matrix::from_fn(4, 4, |row, col| aug[(row, col)]).lu().solve(&(0..4).map(|row| aug[(row, 4)]).collect::<Vec<_>>()).unwrap();
+    assert_eq!(report.solution, lu_solution);
+}
@@ -0,0 +1,88 @@
+use luna::{core::{approx_eq::ApproxEq, rational::Rational}, linalg::{matrix::{matrix::Matrix, matrix_vector_mult::gen_matrix_vector_mult}, solvers::{linear_system_solution::LinearSystemSolution, row_reduction_solver::{isolve_linear_system_rref, solve_linear_system_basis_rref, solve_linear_system_basis_rref_tol, solve_linear_system_rref, solve_linear_system_rref_tol}}}};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col]))
+}
+
+fn coefficients<T: luna::core::number::Number>(aug: &Matrix<T>) -> Matrix<T> {
+    Matrix::from_fn(aug.rows, aug.cols - 1, |row, col| aug[(row, col)])
+}
+
+fn rhs<T: luna::core::number::Number>(aug: &Matrix<T>) -> Vec<T> {
+    (0..aug.rows).map(|row| aug[(row, aug.cols - 1)]).collect()
+}
+
+#[test]
+fn float_pivots_that_are_not_exactly_one() {
+    // The solution is read from the pivot columns the elimination chose, so it does not
+    // depend on round-off leaving the scaled pivots exactly one.
+    let aug = Matrix::from_rows(&[
+        vec![0.1, 0.7, 0.3, 1.1],
+        vec![0.3, 0.1, 0.9, 1.3],
+        vec![0.7, 0.3, 0.1, 1.1],
+    ]);
+    let x = solve_linear_system_rref(&aug).unwrap();
+    assert!(gen_matrix_vector_mult(&coefficients(&aug), &x).approx_eq(&rhs(&aug)));
+    let solution = solve_linear_system_basis_rref(&aug);
+    assert!(solution.is_unique());
+    assert!(solution.particular().unwrap().approx_eq(&x));
+    let mut reduced = aug.clone();
+    let mut answer = vec![0.0; 3];
+    assert!(isolve_linear_system_rref(&mut reduced, &mut answer));
+    assert_eq!(answer, x);
+}
+
+#[test]
+fn round_off_rank_deficiency() {
+    // The third row is 0.1 * row 0 + 0.7 * row 1 in exact arithmetic only.
+    let rows = [[0.3, 0.6, 0.9, 0.2], [0.7, 0.1, 0.4, 0.5]];
+    let third: Vec<f64> = (0..4).map(|col| 0.1 * rows[0][col] + 0.7 * rows[1][col]).collect();
+    let aug = Matrix::from_rows(&[rows[0].to_vec(), rows[1].to_vec(), third]);
+    let solution = solve_linear_system_basis_rref_tol(&aug, 1e-12);
+    assert_eq!(solution.dimension(), Some(1));
+    for t in [-2.0, 0.0, 3.5] {
+        let x = solution.sample(&[t]).unwrap();
+        assert!(gen_matrix_vector_mult(&coefficients(&aug), &x).iter().zip(rhs(&aug).iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    }
+    assert!(solution.verify_tol(&aug, 1e-12));
+    assert!(solve_linear_system_rref_tol(&aug, 1e-12).is_some());
+
+    // Perturbing the right hand side past the tolerance makes the system inconsistent.
+    let mut inconsistent = aug.clone();
+    inconsistent[(2, 3)] += 1e-6;
+    assert!(solve_linear_system_rref_tol(&inconsistent, 1e-12).is_none());
+    assert_eq!(solve_linear_system_basis_rref_tol(&inconsistent, 1e-12), LinearSystemSolution::None);
+}
+
+#[test]
+fn exact_solution_kinds() {
+    let unique = rational(&[&[2, 1, -1, 8], &[-3, -1, 2, -11], &[-2, 1, 2, -3]]);
+    assert_eq!(solve_linear_system_rref(&unique), Some(vec![r(2), r(3), r(-1)]));
+
+    // Free variables between pivots: x1 and x3 are free.
+    let affine = rational(&[&[1, 2, 0, 3, 5], &[0, 0, 1, -1, 2]]);
+    let solution = solve_linear_system_basis_rref(&affine);
+    assert_eq!(solution, LinearSystemSolution::AffineBasis(
+        vec![r(5), r(0), r(2), r(0)],
+        vec![vec![r(-2), r(1), r(0), r(0)], vec![r(-3), r(0), r(1), r(1)]],
+    ));
+    assert!(solution.verify(&affine));
+
+    let homogeneous = rational(&[&[1, 1, 0], &[2, 2, 0]]);
+    assert_eq!(solve_linear_system_basis_rref(&homogeneous), LinearSystemSolution::HomogenousBasis(vec![vec![r(-1), r(1)]]));
+    assert_eq!(solve_linear_system_rref(&homogeneous), Some(vec![r(0), r(0)]));
+
+    let inconsistent = rational(&[&[1, 1, 1], &[1, 1, 2]]);
+    assert_eq!(solve_linear_system_rref(&inconsistent), None);
+    assert_eq!(solve_linear_system_basis_rref(&inconsistent), LinearSystemSolution::None);
+}
+
+#[test]
+#[should_panic]
+fn empty_augmented_matrix_is_rejected() {
+    solve_linear_system_rref(&Matrix::<f64>::zeroes(2, 0));
+}
@@ -0,0 +1,94 @@
+use luna::{core::rational::Rational, linalg::matrix::matrix::Matrix};
+
+fn r(num: i64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den: 1 }
+}
+
+fn rational(rows: &[&[i64]]) -> Matrix<Rational> {
+    Matrix::from_fn(rows.len(), rows[0].len(), |row, col| r(rows[row][col]))
+}
+
+fn dot<T: Copy + std::ops::Mul<Output = T> + std::ops::Add<Output = T>>(a: &[T], b: &[T], zero: T) -> T {
+    a.iter().zip(b.iter()).fold(zero, |acc, (a, b)| acc + *a * *b)
+}
+
+// Whether every vector of basis is a combination of spanning, by the rank not growing.
+fn in_span(spanning: &[Vec<Rational>], basis: &[Vec<Rational>]) -> bool {
+    let stacked: Vec<Vec<Rational>> = spanning.iter().chain(basis.iter()).cloned().collect();
+    Matrix::from_rows(spanning).rank_nullity().0 == Matrix::from_rows(&stacked).rank_nullity().0
+}
+
+#[test]
+fn exact_subspaces_of_rank_deficient_matrix() {
+    // The third row is the sum of the first two, and column 2 is twice column 0.
+    let a = rational(&[&[1, 2, 2, 0], &[0, 1, 0, 1], &[1, 3, 2, 1]]);
+    assert_eq!(a.rank_nullity(), (2, 2));
+    assert!(a.verify_rank_nullity());
+
+    let null = a.null_space();
+    assert_eq!(null.len(), 2);
+    for v in null.iter() {
+        assert!(a.rows_iter().all(|row| dot(row, v, r(0)) == r(0)));
+    }
+    assert_eq!(Matrix::from_rows(&null).rank_nullity().0, 2);
+
+    // Pivot columns are taken from A itself.
+    let columns = a.column_space();
+    assert_eq!(columns, vec![vec![r(1), r(0), r(1)], vec![r(2), r(1), r(3)]]);
+
+    let rows = a.row_space();
+    assert_eq!(rows.len(), 2);
+    let original: Vec<Vec<Rational>> = a.rows_iter().map(|row| row.to_vec()).collect();
+    assert!(in_span(&rows, &original) && in_span(&original, &rows));
+
+    let left = a.left_null_space();
+    assert_eq!(left.len(), 1);
+    for col in 0..a.cols {
+        let column: Vec<Rational> = (0..a.rows).map(|row| a[(row, col)]).collect();
+        assert!(dot(&column, &left[0], r(0)) == r(0));
+    }
+}
+
+#[test]
+fn exact_subspaces_of_full_rank_and_zero_matrices() {
+    let identity = Matrix::<Rational>::identity(3);
+    assert!(identity.null_space().is_empty() && identity.left_null_space().is_empty());
+    assert_eq!(identity.rank_nullity(), (3, 0));
+    let zero = Matrix::<Rational>::zeroes(2, 3);
+    assert_eq!(zero.rank_nullity(), (0, 3));
+    assert!(zero.column_space().is_empty() && zero.row_space().is_empty());
+    assert_eq!(zero.left_null_space().len(), 2);
+    assert!(zero.verify_rank_nullity());
+}
+
+fn orthonormal(basis: &[Vec<f64>]) -> bool {
+    basis.iter().enumerate().all(|(i, u)| {
+        basis.iter().enumerate().all(|(j, v)| (dot(u, v, 0.0) - if i == j { 1.0 } else { 0.0 }).abs() <= 1e-12)
+    })
+}
+
+#[test]
+fn numerical_subspaces_with_tolerance() {
+    // Rank 2 up to a perturbation far below the tolerance.
+    let mut a = Matrix::from_rows(&[vec![1.0, 2.0, 2.0, 0.0], vec![0.0, 1.0, 0.0, 1.0], vec![1.0, 3.0, 2.0, 1.0]]);
+    a[(2, 3)] += 1e-14;
+    let tol = 1e-10;
+    assert_eq!(a.rank_tol(tol), 2);
+    assert_eq!(a.rank_nullity_tol(tol), (2, 2));
+    assert_eq!(a.rank_tol(0.0), 3);
+
+    let null = a.null_space_tol(tol);
+    let column = a.column_space_tol(tol);
+    let row = a.row_space_tol(tol);
+    let left = a.left_null_space_tol(tol);
+    assert_eq!((null.len(), column.len(), row.len(), left.len()), (2, 2, 2, 1));
+    for basis in [&null, &column, &row, &left] {
+        assert!(orthonormal(basis));
+    }
+    for v in null.iter() {
+        assert!(a.rows_iter().all(|r| dot(r, v, 0.0).abs() <= 1e-12));
+    }
+    // The row space and the null space are orthogonal complements, as are column and left null.
+    assert!(row.iter().all(|u| null.iter().all(|v| dot(u, v, 0.0).abs() <= 1e-12)));
+    assert!(column.iter().all(|u| left.iter().all(|v| dot(u, v, 0.0).abs() <= 1e-12)));
+}
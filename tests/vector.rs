@@ -0,0 +1,130 @@
+use luna::{core::{approx_eq::ApproxEq, complex::Complex, rational::Rational}, linalg::{matrix::matrix::Matrix, vector::vector::{Orientation, Vector}}};
+
+fn r(num: i64, den: u64) -> Rational {
+    Rational { pos: num >= 0, num: num.unsigned_abs(), den }
+}
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex { re, im }
+}
+
+#[test]
+fn same_orientation_arithmetic() {
+    let a = Vector::column(vec![1, 2, 3]);
+    let b = Vector::column(vec![4, -5, 6]);
+    assert_eq!(&a + &b, Vector::column(vec![5, -3, 9]));
+    assert_eq!(a.clone() - b.clone(), Vector::column(vec![-3, 7, -3]));
+    let mut m = a.transpose();
+    m += b.transpose();
+    m -= &a.transpose();
+    assert_eq!(m, b.transpose());
+    assert!(m.is_row());
+    assert_eq!(a.hadamard_mult(&b), Vector::column(vec![4, -10, 18]));
+    assert_eq!(-&a, Vector::column(vec![-1, -2, -3]));
+    assert_eq!(&a * 2, 2 * a.clone());
+}
+
+#[test]
+#[should_panic]
+fn adding_row_to_column_panics() {
+    let _ = Vector::column(vec![1, 2]) + Vector::row(vec![3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn subtracting_column_from_row_panics() {
+    let mut a = Vector::row(vec![1, 2]);
+    a -= Vector::column(vec![3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn hadamard_of_row_and_column_panics() {
+    Vector::row(vec![1, 2]).hadamard_mult(&Vector::column(vec![3, 4]));
+}
+
+#[test]
+fn products_ignore_orientation() {
+    let a = Vector::column(vec![1, 0, 2]);
+    let b = Vector::row(vec![3, 4, -1]);
+    assert_eq!(a.dot(&b), 1);
+    assert_eq!(a.transpose().dot(&b), 1);
+    assert_eq!(Vector::column(vec![1, 0, 0]).cross(&Vector::row(vec![0, 1, 0])).data, vec![0, 0, 1]);
+    assert_eq!(a.cross(&b).data, vec![-8, 7, 4]);
+    assert_eq!(a.cross(&b).dot(&a), 0);
+    let outer = a.outer(&b);
+    assert_eq!(outer.shape(), (3, 3));
+    assert_eq!(outer.data, vec![3, 4, -1, 0, 0, 0, 6, 8, -2]);
+}
+
+#[test]
+fn norms_and_normalize() {
+    let v = Vector::column(vec![3.0, -4.0, 0.0]);
+    assert_eq!(v.norm_l1(), 7.0);
+    assert_eq!(v.norm_l2(), 5.0);
+    assert_eq!(v.norm_linf(), 4.0);
+    assert!(v.norm_lp(3.0).approx_eq(&91f64.powf(1.0 / 3.0)));
+    assert!(v.norm_lp(1.0).approx_eq(&7.0));
+    assert!(v.normalize().approx_eq(&Vector::column(vec![0.6, -0.8, 0.0])));
+    // Scaling keeps huge and tiny entries finite.
+    assert!(Vector::column(vec![3e300, 4e300]).norm_l2().approx_eq(&5e300));
+    assert!(Vector::column(vec![3e-300, 4e-300]).norm_l2().approx_eq(&5e-300));
+
+    let z = Vector::column(vec![c(3.0, 4.0), c(0.0, -12.0)]);
+    assert_eq!(z.norm_l1(), 17.0);
+    assert_eq!(z.norm_l2(), 13.0);
+    assert_eq!(z.norm_linf(), 12.0);
+    assert!(z.normalize().norm_l2().approx_eq(&1.0));
+}
+
+#[test]
+fn geometry() {
+    let x = Vector::column(vec![1.0, 0.0]);
+    let y = Vector::column(vec![1.0, 1.0]);
+    assert!(x.angle_between(&y).approx_eq(&std::f64::consts::FRAC_PI_4));
+    assert!(x.angle_between(&(-&x)).approx_eq(&std::f64::consts::PI));
+    assert_eq!(x.angle_between(&x), 0.0);
+
+    let a = Vector::column(vec![r(2, 1), r(3, 1), r(-1, 1)]);
+    let onto = Vector::column(vec![r(1, 1), r(1, 1), r(0, 1)]);
+    let proj = a.projection(&onto);
+    assert_eq!(proj, Vector::column(vec![r(5, 2), r(5, 2), r(0, 1)]));
+    let rej = a.rejection(&onto);
+    assert_eq!(&proj + &rej, a);
+    assert_eq!(rej.dot(&onto), r(0, 1));
+}
+
+#[test]
+fn hermitian_products() {
+    let a = Vector::column(vec![c(1.0, 1.0), c(0.0, 2.0)]);
+    let b = Vector::column(vec![c(2.0, 0.0), c(1.0, -1.0)]);
+    // conj(1+i)*2 + conj(2i)*(1-i) = 2 - 2i - 2 - 2i
+    assert!(a.hermitian_dot(&b).re == 0.0 && a.hermitian_dot(&b).im == -4.0);
+    let self_dot = a.hermitian_dot(&a);
+    assert!(self_dot.re == 6.0 && self_dot.im == 0.0);
+    let rej = b.rejection(&a);
+    assert!(a.hermitian_dot(&rej).abs() < 1e-12);
+}
+
+#[test]
+fn matrix_interaction() {
+    let m = Matrix::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]]);
+    let col = Vector::column(vec![1, 0, -1]);
+    assert_eq!(&m * &col, Vector::column(vec![-2, -2]));
+    let row = Vector::row(vec![1, -1]);
+    assert_eq!(&row * &m, Vector::row(vec![-3, -3, -3]));
+    assert_eq!(col.to_matrix().shape(), (3, 1));
+    assert_eq!(row.to_matrix().shape(), (1, 2));
+    assert_eq!(Vector::from_matrix(&row.to_matrix()), row);
+    assert_eq!(Matrix::from(col.clone()).data, col.data);
+    assert_eq!(Vector::<i64>::from(vec![1, 2]).orientation, Orientation::Column);
+    assert_eq!(col.to_string(), "[1, 0, -1]^T");
+    assert_eq!(row.to_string(), "[1, -1]");
+}
+
+#[test]
+#[should_panic]
+fn matrix_times_row_vector_panics() {
+    let m = Matrix::from_rows(&[vec![1, 2], vec![3, 4]]);
+    let _ = &m * &Vector::row(vec![1, 1]);
+}